@@ -0,0 +1,280 @@
+use std::{fmt::Display, io::Write};
+
+use crate::{
+    count_lines, messages,
+    output::{self, PromptWriter},
+    print_prompt, read_input, term, theme,
+};
+
+const VISIBLE_MATCHES: usize = 10;
+
+/// A fuzzy match of a search pattern against an item's text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FuzzyMatch {
+    index: usize,
+    score: i32,
+    positions: Vec<usize>,
+}
+
+/// Scores how well `pattern` matches `text` as a case-insensitive subsequence, returning the score and the character positions that matched, or None if it does not match.
+fn fuzzy_score(pattern: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let text: Vec<char> = text.chars().collect();
+    let lowered: Vec<char> = text
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    if pattern.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let mut best: Option<(i32, Vec<usize>)> = None;
+    for start in 0..lowered.len() {
+        if lowered[start] != pattern[0] {
+            continue;
+        }
+        let mut positions = vec![start];
+        let mut next = start + 1;
+        for pattern_char in pattern.iter().skip(1) {
+            match lowered[next..].iter().position(|c| c == pattern_char) {
+                Some(offset) => {
+                    positions.push(next + offset);
+                    next += offset + 1;
+                }
+                None => break,
+            }
+        }
+        if positions.len() < pattern.len() {
+            break;
+        }
+        let score = score_positions(&text, &positions);
+        if best
+            .as_ref()
+            .is_none_or(|(best_score, _)| score > *best_score)
+        {
+            best = Some((score, positions));
+        }
+    }
+    best
+}
+
+fn score_positions(text: &[char], positions: &[usize]) -> i32 {
+    let mut score = 0;
+    for (i, &position) in positions.iter().enumerate() {
+        score += 16;
+        if i > 0 {
+            let gap = position - positions[i - 1] - 1;
+            if gap == 0 {
+                score += 8;
+            } else {
+                score -= gap.min(8) as i32;
+            }
+        }
+        let at_boundary = position == 0
+            || !text[position - 1].is_alphanumeric()
+            || (text[position - 1].is_lowercase() && text[position].is_uppercase());
+        if at_boundary {
+            score += 8;
+        }
+    }
+    score - positions[0].min(8) as i32
+}
+
+/// Returns the items matching `pattern`, best matches first.
+fn fuzzy_filter(pattern: &str, texts: &[String]) -> Vec<FuzzyMatch> {
+    let mut matches: Vec<FuzzyMatch> = texts
+        .iter()
+        .enumerate()
+        .filter_map(|(index, text)| {
+            fuzzy_score(pattern, text).map(|(score, positions)| FuzzyMatch {
+                index,
+                score,
+                positions,
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(texts[a.index].len().cmp(&texts[b.index].len()))
+            .then(a.index.cmp(&b.index))
+    });
+    matches
+}
+
+fn highlight(text: &str, positions: &[usize]) -> String {
+    let mut highlighted = String::new();
+    for (i, c) in text.chars().enumerate() {
+        if positions.contains(&i) {
+            highlighted.push_str(&theme::highlight(c));
+        } else {
+            highlighted.push(c);
+        }
+    }
+    highlighted
+}
+
+/// Prompts the user to pick an item from a list by typing a search term which narrows the list with fuzzy matching, and returns the chosen item, or None if the user cancelled.
+///
+/// When attached to a terminal, the matches update with every keypress and the matched characters are shown in the theme's `highlight` style: the arrow keys move the cursor, Enter picks the item under the cursor and Escape cancels. Otherwise, the user is asked to enter a search term and then the number of one of the listed matches.
+///
+/// # Arguments
+///
+/// * `prompt` - An option that can contain a string slice which holds the prompt to present the user with.
+/// * `items` - An array of items of a type with 'Display' trait
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let items = vec!["Hippo", "Elephant", "Lion", "Crocodile", "Giraffe"];
+/// let picked = fuzzy_select(Some("Pick an animal:"), &items);
+/// ```
+pub fn fuzzy_select<'a, T: Display>(prompt: Option<&str>, items: &'a [T]) -> Option<&'a T> {
+    output::begin_prompt();
+    print_prompt(prompt);
+    let texts: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+    let index = match term::RawMode::enable() {
+        Some(raw_mode) => run_fuzzy_select(raw_mode, &texts),
+        None => read_fuzzy_select(&texts),
+    };
+    index.map(|index| &items[index])
+}
+
+/// Draws the query, the visible matches and the key hint of a fuzzy select. Returns the number of lines drawn, including lines that wrap.
+fn render_fuzzy_select(
+    query: &str,
+    texts: &[String],
+    matches: &[FuzzyMatch],
+    cursor: usize,
+) -> usize {
+    let mut output = PromptWriter;
+    let mut lines = Vec::new();
+    lines.push(format!("> {}", query));
+    let first = cursor.saturating_sub(VISIBLE_MATCHES - 1);
+    for (i, fuzzy_match) in matches.iter().enumerate().skip(first).take(VISIBLE_MATCHES) {
+        let pointer = if i == cursor {
+            theme::selected(">")
+        } else {
+            String::from(" ")
+        };
+        lines.push(format!(
+            "{} {}",
+            pointer,
+            highlight(&texts[fuzzy_match.index], &fuzzy_match.positions)
+        ));
+    }
+    lines.push(theme::hint(messages::message(
+        |m| &m.fuzzy_keys,
+        &[("matches", &matches.len()), ("total", &texts.len())],
+    )));
+    for line in &lines {
+        let _ = writeln!(output, "{}", line);
+    }
+    let _ = output.flush();
+    lines.iter().map(|line| count_lines(line)).sum()
+}
+
+fn run_fuzzy_select(raw_mode: term::RawMode, texts: &[String]) -> Option<usize> {
+    let mut query = String::new();
+    let mut matches = fuzzy_filter(&query, texts);
+    let mut cursor = 0;
+    let mut lines = render_fuzzy_select(&query, texts, &matches, cursor);
+    loop {
+        match term::read_key() {
+            term::Key::Up => cursor = cursor.saturating_sub(1),
+            term::Key::Down | term::Key::Tab if cursor + 1 < matches.len() => cursor += 1,
+            term::Key::Enter => {
+                if let Some(fuzzy_match) = matches.get(cursor) {
+                    return Some(fuzzy_match.index);
+                }
+            }
+            term::Key::Escape => return None,
+            term::Key::CtrlC => term::interrupt(&raw_mode),
+            term::Key::Backspace => {
+                query.pop();
+                matches = fuzzy_filter(&query, texts);
+                cursor = 0;
+            }
+            term::Key::Char(c) if !c.is_control() => {
+                query.push(c);
+                matches = fuzzy_filter(&query, texts);
+                cursor = 0;
+            }
+            _ => {}
+        }
+        term::erase_lines(lines);
+        lines = render_fuzzy_select(&query, texts, &matches, cursor);
+    }
+}
+
+fn read_fuzzy_select(texts: &[String]) -> Option<usize> {
+    let mut matches: Vec<FuzzyMatch> = Vec::new();
+    let mut input = String::new();
+    prompt_println!("{}", messages::message(|m| &m.fuzzy_prompt, &[]));
+    loop {
+        match read_input(&mut input) {
+            Ok(_n) => {
+                let trimmed = input.trim();
+                if trimmed.is_empty() {
+                    return None;
+                }
+                if let Ok(number) = trimmed.parse::<usize>() {
+                    if number >= 1 && number <= matches.len().min(VISIBLE_MATCHES) {
+                        return Some(matches[number - 1].index);
+                    }
+                }
+                matches = fuzzy_filter(trimmed, texts);
+                if matches.is_empty() {
+                    prompt_println!(
+                        "{}",
+                        theme::error(messages::message(
+                            |m| &m.fuzzy_no_matches,
+                            &[("input", &trimmed)]
+                        ))
+                    );
+                } else {
+                    for (i, fuzzy_match) in matches.iter().take(VISIBLE_MATCHES).enumerate() {
+                        prompt_println!("{}. {}", i + 1, texts[fuzzy_match.index]);
+                    }
+                    prompt_println!(
+                        "{}",
+                        theme::hint(messages::message(
+                            |m| &m.fuzzy_matches,
+                            &[("matches", &matches.len()), ("total", &texts.len())]
+                        ))
+                    );
+                }
+            }
+            Err(error) => panic!("Unexpected stdin error while reading input: {}", error),
+        }
+        input.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("", "Hippo"), Some((0, vec![])));
+        assert_eq!(fuzzy_score("hpo", "Hippo").unwrap().1, vec![0, 2, 4]);
+        assert_eq!(fuzzy_score("HIP", "hippo").unwrap().1, vec![0, 1, 2]);
+        assert_eq!(fuzzy_score("xyz", "Hippo"), None);
+        assert_eq!(fuzzy_score("ppoh", "Hippo"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_filter_ordering() {
+        let texts = vec![
+            String::from("Rhinoceros"),
+            String::from("Hippo"),
+            String::from("Big Hippo"),
+            String::from("Hyena"),
+        ];
+        let matches = fuzzy_filter("hip", &texts);
+        let order: Vec<usize> = matches.iter().map(|m| m.index).collect();
+        assert_eq!(order, vec![1, 2]);
+        assert_eq!(fuzzy_filter("h", &texts).len(), 4);
+    }
+}
@@ -1,16 +1,79 @@
-#![feature(int_roundings)]
+/// Prints to the stream set with `set_prompt_output`, like `print!`.
+macro_rules! prompt_print {
+    ($($arg:tt)*) => {{
+        use std::io::Write as _;
+        let _ = write!($crate::output::PromptWriter, $($arg)*);
+    }};
+}
+
+/// Prints a line to the stream set with `set_prompt_output`, like `println!`.
+macro_rules! prompt_println {
+    ($($arg:tt)*) => {{
+        use std::io::Write as _;
+        let _ = writeln!($crate::output::PromptWriter, $($arg)*);
+    }};
+}
+
+mod fuzzy;
+mod list;
+mod locale;
+mod messages;
+mod number;
+mod output;
+mod pager;
+mod paginator;
+mod source;
+mod table;
+mod term;
+mod text;
+mod theme;
+mod tree;
+
+pub use fuzzy::fuzzy_select;
+pub use list::{print_list_with, ListMarker, ListOptions};
+pub use locale::Locale;
+pub use messages::{messages, set_locale, set_messages, with_messages, Messages};
+pub use number::{number_parsing, set_number_parsing, NumberError, NumberKind, NumberParsing};
+#[cfg(feature = "serde")]
+pub use output::print_records;
+pub use output::{
+    output_format, print_list_as, prompt_output, set_output_format, set_prompt_output,
+    OutputFormat, PromptOutput, OUTPUT_FORMAT_VAR,
+};
+pub use paginator::{PageSize, PagerScreen, Paginator};
+pub use source::{paginated_source, IterSource, PageSource};
+pub use table::{paginated_table, print_table, Alignment, Borders, Table};
+pub use text::{follow_reader, follow_text, page_reader, page_text};
+pub use theme::{set_theme, theme, Color, Style, Theme};
+pub use tree::{browse_tree, print_tree, TreeGuides, TreeNode};
 
-use std::{any::type_name, fmt::Display, io, str::FromStr};
+use std::{
+    fmt::Display,
+    io::{self, IsTerminal, Write},
+    str::FromStr,
+};
+
+use pager::{page_footer, Pager, PagerCommand, PagerCommands};
+use unicode_width::UnicodeWidthChar;
 
 fn print_prompt(prompt: Option<&str>) -> bool {
     match prompt {
         Some(input_prompt) => {
-            println!("{}", input_prompt);
-            return true;
+            prompt_println!("{}", theme::prompt(input_prompt));
+            true
         }
-        None => {
-            return false;
+        None => false,
+    }
+}
+
+/// Prints the header message of a list of data to stdout, unlike prompts which are written to the stream set with `set_prompt_output`.
+fn print_header(header_message: Option<&str>) -> bool {
+    match header_message {
+        Some(header_message) => {
+            println!("{}", header_message);
+            true
         }
+        None => false,
     }
 }
 
@@ -19,27 +82,36 @@ fn check_length(length: &usize, max_length: Option<i32>) -> bool {
         Some(max) => {
             let input_length = *length as i32;
             if input_length > max {
-                println!(
-                    "Your input is {} characters higher than the {} character limit. Please try again.",
-                    input_length - max,
-                    length
+                prompt_println!(
+                    "{}",
+                    theme::error(messages::message(
+                        |m| &m.input_too_long,
+                        &[
+                            ("length", &input_length),
+                            ("over", &(input_length - max)),
+                            ("limit", &max),
+                        ]
+                    ))
                 );
-                return false;
+                false
             } else {
-                return true;
+                true
             }
         }
-        None => return true,
+        None => true,
     }
 }
 
 fn check_empty(length: &usize, can_be_empty: bool) -> bool {
     let input_length = *length as i32;
     if input_length <= 0 && !can_be_empty {
-        println!("Your input cannot be empty.");
-        return false;
+        prompt_println!(
+            "{}",
+            theme::error(messages::message(|m| &m.input_empty, &[]))
+        );
+        false
     } else {
-        return true;
+        true
     }
 }
 
@@ -48,36 +120,36 @@ fn check_min_max<T: PartialOrd + Display>(
     min_value: Option<T>,
     max_value: Option<T>,
 ) -> bool {
-    match min_value {
-        Some(min) => {
-            if number < min {
-                println!(
-                    "Your input ({}) is lower than the minimum allowed value of {}.",
-                    number, min
-                );
-                return false;
-            }
+    if let Some(min) = min_value {
+        if number < min {
+            prompt_println!(
+                "{}",
+                theme::error(messages::message(
+                    |m| &m.below_min,
+                    &[("input", &number), ("min", &min)]
+                ))
+            );
+            return false;
         }
-        None => {}
     }
-    match max_value {
-        Some(max) => {
-            if number > max {
-                println!(
-                    "Your input ({}) is larger than the maximum allowed value of {}.",
-                    number, max
-                );
-                return false;
-            }
+    if let Some(max) = max_value {
+        if number > max {
+            prompt_println!(
+                "{}",
+                theme::error(messages::message(
+                    |m| &m.above_max,
+                    &[("input", &number), ("max", &max)]
+                ))
+            );
+            return false;
         }
-        None => {}
     }
-    return true;
+    true
 }
 
 fn check_number_is_a_choice<T: PartialOrd + Display>(
     number: &T,
-    choices: &Vec<T>,
+    choices: &[T],
     show_choices_on_failure: bool,
 ) -> bool {
     for choice in choices.iter() {
@@ -86,16 +158,25 @@ fn check_number_is_a_choice<T: PartialOrd + Display>(
         }
     }
     if show_choices_on_failure {
-        print!("Your input ({}) is not an option of the choices: ", number);
-        for choice in choices.iter() {
-            print!("{}, ", choice);
-        }
-        print!("\n");
+        let choices: Vec<String> = choices.iter().map(|choice| choice.to_string()).collect();
+        prompt_println!(
+            "{}",
+            theme::error(messages::message(
+                |m| &m.invalid_choice_with_choices,
+                &[("input", number), ("choices", &choices.join(", "))]
+            ))
+        );
     } else {
-        println!("Your input ({}) is not a valid choice.", number);
+        prompt_println!(
+            "{}",
+            theme::error(messages::message(
+                |m| &m.invalid_choice,
+                &[("input", number)]
+            ))
+        );
     }
 
-    return false;
+    false
 }
 
 fn check_string_is_a_choice(
@@ -105,26 +186,39 @@ fn check_string_is_a_choice(
     show_choices_on_failure: bool,
 ) -> bool {
     for choice in choices.iter() {
-        if input == choice {
-            return true;
-        } else if input.to_lowercase() == choice.to_lowercase() && !case_sensitive {
+        if input == choice || (!case_sensitive && input.to_lowercase() == choice.to_lowercase()) {
             return true;
         }
     }
     if show_choices_on_failure {
-        print!("Your input ({}) is not an option of the choices: ", input);
-        for choice in choices.iter() {
-            print!("{}, ", choice);
-        }
-        print!("\n");
+        prompt_print!(
+            "{} ",
+            theme::error(messages::message(
+                |m| &m.invalid_choice_with_choices,
+                &[("input", input), ("choices", &choices.join(", "))]
+            ))
+        );
     } else {
-        print!("Your input ({}) is not a valid choice. ", input);
+        prompt_print!(
+            "{} ",
+            theme::error(messages::message(
+                |m| &m.invalid_choice,
+                &[("input", input)]
+            ))
+        );
     }
-    print!("(Case Sensitive: {})\n", case_sensitive);
-    return false;
+    prompt_println!(
+        "{}",
+        theme::hint(if case_sensitive {
+            messages::message(|m| &m.case_sensitive, &[])
+        } else {
+            messages::message(|m| &m.case_insensitive, &[])
+        })
+    );
+    false
 }
 
-/// Displays a list of items.
+/// Displays a list of items, one per line, in the format from `output_format`, such as JSON when `SIMPLE_CLI_OUTPUT` is set to `json`. Use `print_list_with` to number, bullet, indent or lay out the items in a grid, or `print_list_as` to choose the format.
 ///
 /// # Arguments
 ///
@@ -140,13 +234,10 @@ fn check_string_is_a_choice(
 ///
 /// ```
 pub fn print_list<T: Display>(header_message: Option<&str>, items: &[T]) {
-    print_prompt(header_message);
-    for i in 0..items.len() {
-        println!("{}", items[i])
-    }
+    print_list_as(header_message, items, None);
 }
 
-/// Clears all printed lines from the terminal.
+/// Clears all printed lines from the terminal by resetting it, which also clears its scrollback. Use `clear_screen` or `clear_last_prompt` to keep the scrollback. Does nothing if prompts are not written to a terminal, such as when the output is redirected to a file.
 ///
 /// # Example
 ///
@@ -155,17 +246,67 @@ pub fn print_list<T: Display>(header_message: Option<&str>, items: &[T]) {
 /// clear_terminal();
 /// ```
 pub fn clear_terminal() {
-    print!("{esc}c", esc = 27 as char);
+    if term::escapes_enabled() {
+        prompt_print!("{esc}c", esc = 27 as char);
+    }
+}
+
+/// Clears the visible screen and moves the cursor to the top, leaving the terminal's scrollback intact. Does nothing if prompts are not written to a terminal.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// clear_screen();
+/// ```
+pub fn clear_screen() {
+    term::clear_screen();
+}
+
+/// Erases a number of lines above the cursor, along with anything printed after them. Does nothing if prompts are not written to a terminal.
+///
+/// # Arguments
+///
+/// * `lines` - The number of lines to erase.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// println!("Working...");
+/// clear_lines(1);
+/// println!("Done!");
+/// ```
+pub fn clear_lines(lines: usize) {
+    term::erase_lines(lines);
+}
+
+/// Erases the lines printed for the most recent prompt or page, including the user's answers, so the next output appears in their place. Does nothing if prompts are not written to a terminal.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let name = get_string(Some("Enter your name:"), None, None, false);
+/// clear_last_prompt();
+/// println!("Hello, {}!", name);
+/// ```
+pub fn clear_last_prompt() {
+    term::erase_lines(output::take_prompt_lines());
 }
 
 /// Displays a paginated list of items.
 ///
+/// When attached to a terminal, the list reacts to single keypresses and redraws in place: N, Space, the right arrow or Page Down show the next page, P, the left arrow or Page Up the previous page, Home and End the first and last page, and Q exits. Otherwise, the user types commands followed by Enter.
+///
+/// While viewing the list, the user can enter N or P to move between pages, F or L to jump to the first or last page, a page number to jump to that page, or a relative jump such as +5 or -3. The user can also enter / to filter the items by a search term, which only shows items whose text contains the term. The search is case-insensitive unless the term contains an uppercase letter. Entering C clears the search.
+///
 /// # Arguments
 ///
 /// * `header_message` - An option that can contain a string slice which holds a header message for the paginated list.
 /// * `items` - An array of items of a type with 'Display' trait
-/// * `items_per_page` - The number of items that will be displayed per page.
-/// * `clear_on_update` - A boolean which denotes whether the terminal should clear each time the user navigates to a new page. This is helpful when making command-line apps that "re-render" a single display.
+/// * `items_per_page` - The number of items that will be displayed per page, or `PageSize::Auto` to fit the page to the terminal's height.
+/// * `clear_on_update` - A boolean which denotes whether the terminal should clear each time the user navigates to a new page. Pass `PagerScreen::AlternateScreen` instead to show the pages on the alternate screen and restore the terminal's previous contents on exit. This is helpful when making command-line apps that "re-render" a single display.
 ///
 /// # Examples
 ///
@@ -181,68 +322,246 @@ pub fn clear_terminal() {
 pub fn paginated_list<T: Display>(
     header_message: Option<&str>,
     items: &[T],
-    items_per_page: i32,
-    clear_on_update: bool,
+    items_per_page: impl Into<PageSize>,
+    clear_on_update: impl Into<PagerScreen>,
 ) {
-    if items_per_page <= 0 {
-        panic!("Items per page must be greater than zero.");
-    }
-    let mut quit = false;
-    let number_of_items = items.len() as i32;
-    let mut current_page: i32 = 1;
-    let mut number_of_pages: i32 = number_of_items.div_ceil(items_per_page);
-    if number_of_pages == 0 {
-        number_of_pages = 1;
-    }
-    while !quit {
-        print_prompt(header_message);
-        let end_index: i32;
-        if current_page == number_of_pages {
-            end_index = number_of_items;
-        } else {
-            end_index = current_page * items_per_page;
-        }
-        if number_of_items > 0 {
-            for i in ((current_page - 1) * items_per_page)..end_index {
-                println!("{}", items[i as usize]);
-            }
-        }
-        println!("(Page {} of {})", current_page, number_of_pages);
-        let user_input = select_string_from_choices(
-            Some("Press N to view the next page, P for previous, S for a specific page, or E to Exit."),
-            Some("Press N to view the next page, P for previous, S for a specific page, or E to Exit."),
-            vec!["N", "P", "S", "E"],
-            false,
-            true
-        );
-        match user_input.to_lowercase().as_str() {
-            "n" => {
-                if current_page < number_of_pages {
-                    current_page += 1;
+    run_paginated_list(
+        header_message,
+        items,
+        items_per_page,
+        clear_on_update,
+        false,
+    );
+}
+
+/// Displays a paginated list of items and lets the user select one of the items on the current page, either by its number on the page or by its name. Returns the selected item, or None if the user exited without selecting one.
+///
+/// Navigation works the same as in `paginated_list`, except that a bare number selects an item on the current page, so jumping to a page number is done by entering S followed by the page number, such as S 4.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the paginated list.
+/// * `items` - An array of items of a type with 'Display' trait
+/// * `items_per_page` - The number of items that will be displayed per page, or `PageSize::Auto` to fit the page to the terminal's height.
+/// * `clear_on_update` - A boolean which denotes whether the terminal should clear each time the user navigates to a new page. Pass `PagerScreen::AlternateScreen` instead to show the pages on the alternate screen and restore the terminal's previous contents on exit. This is helpful when making command-line apps that "re-render" a single display.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let items = vec!["Moe", "Larry", "Curly"];
+/// match paginated_select(Some("Pick a stooge:"), &items, 2, true) {
+///     Some(stooge) => println!("You picked {}!", stooge),
+///     None => println!("You didn't pick anyone."),
+/// }
+/// ```
+pub fn paginated_select<'a, T: Display>(
+    header_message: Option<&str>,
+    items: &'a [T],
+    items_per_page: impl Into<PageSize>,
+    clear_on_update: impl Into<PagerScreen>,
+) -> Option<&'a T> {
+    run_paginated_list(header_message, items, items_per_page, clear_on_update, true)
+        .map(|index| &items[index])
+}
+
+/// Returns true if `text` contains `term`. The match is case-insensitive unless the term contains an uppercase letter.
+fn matches_filter(text: &str, term: &str) -> bool {
+    if term.chars().any(char::is_uppercase) {
+        text.contains(term)
+    } else {
+        text.to_lowercase().contains(&term.to_lowercase())
+    }
+}
+
+fn filter_items(texts: &[String], filter: Option<&str>) -> Vec<usize> {
+    match filter {
+        Some(term) => (0..texts.len())
+            .filter(|&i| matches_filter(&texts[i], term))
+            .collect(),
+        None => (0..texts.len()).collect(),
+    }
+}
+
+/// Returns the number of terminal lines taken up by printed text, including lines that wrap past the terminal's width.
+fn count_lines(text: &str) -> usize {
+    count_wrapped_lines(text, term::width())
+}
+
+/// Returns the number of lines taken up by text on a terminal `width` columns wide. Escape sequences such as colors take up no space.
+fn count_wrapped_lines(text: &str, width: usize) -> usize {
+    text.split('\n')
+        .map(|line| display_width(line).div_ceil(width.max(1)).max(1))
+        .sum()
+}
+
+/// Returns the number of columns a line of text takes up, skipping escape sequences. East Asian wide characters, such as those in the Japanese messages, take up two columns.
+fn display_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
                 }
             }
-            "p" => {
-                if current_page > 1 {
-                    current_page -= 1;
+        } else if !c.is_control() {
+            width += c.width().unwrap_or(0);
+        }
+    }
+    width
+}
+
+/// The pager behind `paginated_list` and `paginated_select`.
+struct ListPager {
+    texts: Vec<String>,
+    selectable: bool,
+    filter: Option<String>,
+    visible: Vec<usize>,
+}
+
+impl Pager for ListPager {
+    type Output = usize;
+
+    fn paginator(&mut self, page_size: usize) -> Paginator {
+        Paginator::new(self.visible.len(), page_size)
+    }
+
+    fn commands(&self) -> PagerCommands {
+        PagerCommands {
+            search: true,
+            ..PagerCommands::default()
+        }
+    }
+
+    fn page_lines(&mut self, paginator: &Paginator) -> Vec<String> {
+        paginator
+            .page_items(&self.visible)
+            .iter()
+            .enumerate()
+            .map(|(i, &item)| {
+                if self.selectable {
+                    format!("{}. {}", i + 1, self.texts[item])
+                } else {
+                    self.texts[item].clone()
                 }
-            }
-            "s" => {
-                current_page = select_number_from_choices(
-                    Some("Enter the page you would like to view."),
-                    Some("Enter the page you would like to view."),
-                    (1..(number_of_pages + 1)).collect(),
-                    false,
-                );
-            }
-            "e" => {
-                quit = true;
-            }
-            _ => {}
+            })
+            .collect()
+    }
+
+    fn prompts(&self) -> bool {
+        self.selectable
+    }
+
+    fn footer(&self, paginator: &Paginator) -> String {
+        match &self.filter {
+            Some(term) => messages::message(
+                |m| &m.filtered_page_footer,
+                &[
+                    ("page", &paginator.current_page()),
+                    ("pages", &paginator.page_count()),
+                    ("matches", &self.visible.len()),
+                    ("total", &self.texts.len()),
+                    ("filter", term),
+                ],
+            ),
+            None => page_footer(paginator.current_page(), paginator.page_count()),
         }
-        if clear_on_update {
-            clear_terminal();
+    }
+
+    fn page_labels(&self, paginator: &Paginator) -> Option<Vec<String>> {
+        if !self.selectable {
+            return None;
+        }
+        let page_items = paginator.page_items(&self.visible);
+        Some(page_items.iter().map(|&i| self.texts[i].clone()).collect())
+    }
+
+    fn key_hint(&self) -> String {
+        if self.selectable {
+            messages::message(|m| &m.select_keys, &[])
+        } else {
+            messages::message(|m| &m.list_keys, &[])
+        }
+    }
+
+    fn line_help(&self) -> String {
+        if self.selectable {
+            messages::message(|m| &m.select_help, &[])
+        } else {
+            messages::message(|m| &m.list_help, &[])
         }
     }
+
+    fn search_prompt(&self) -> String {
+        messages::message(|m| &m.list_search_prompt, &[])
+    }
+
+    fn handle(&mut self, command: PagerCommand, paginator: &mut Paginator) -> Option<usize> {
+        match command {
+            PagerCommand::Select(index) => return Some(paginator.page_items(&self.visible)[index]),
+            PagerCommand::Filter(term) => {
+                self.filter = term.filter(|term| !term.is_empty());
+            }
+            PagerCommand::ClearFilter => self.filter = None,
+            _ => return None,
+        }
+        self.visible = filter_items(&self.texts, self.filter.as_deref());
+        paginator.set_item_count(self.visible.len());
+        paginator.first();
+        None
+    }
+}
+
+fn run_paginated_list<T: Display>(
+    header_message: Option<&str>,
+    items: &[T],
+    items_per_page: impl Into<PageSize>,
+    clear_on_update: impl Into<PagerScreen>,
+    selectable: bool,
+) -> Option<usize> {
+    let texts: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+    let mut pager = ListPager {
+        visible: filter_items(&texts, None),
+        texts,
+        selectable,
+        filter: None,
+    };
+    pager::run_pager(
+        header_message,
+        &mut pager,
+        items_per_page.into(),
+        clear_on_update.into(),
+    )
+}
+
+/// Panics because stdin has no more input, since otherwise the prompt would be repeated forever.
+fn end_of_input() -> ! {
+    if io::stdin().is_terminal() {
+        panic!("Reached the end of input while waiting for an answer.");
+    }
+    panic!("Unable to prompt for input: stdin is not a terminal and has no more input to read answers from.");
+}
+
+/// Reads a line of input from stdin. Panics if stdin has no more input.
+fn read_input(input: &mut String) -> io::Result<usize> {
+    let bytes = read_line(input)?;
+    if bytes == 0 {
+        end_of_input();
+    }
+    Ok(bytes)
+}
+
+/// Reads a line of input from stdin, returning 0 if stdin has no more input.
+fn read_line(input: &mut String) -> io::Result<usize> {
+    let bytes = io::stdin().read_line(input)?;
+    if bytes > 0 && io::stdin().is_terminal() {
+        output::count_prompt_lines(1);
+    }
+    Ok(bytes)
 }
 
 /// Prompts the user for a string input and returns it.
@@ -256,7 +575,7 @@ pub fn paginated_list<T: Display>(
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use simple_cli::*;
 /// let input = get_string(Some("Enter your name:"), Some("Enter your name:"), Some(25), false);
 /// ```
@@ -266,10 +585,11 @@ pub fn get_string(
     max_length: Option<i32>,
     can_be_empty: bool,
 ) -> String {
+    output::begin_prompt();
     print_prompt(prompt);
     let mut input = String::new();
     loop {
-        match io::stdin().read_line(&mut input) {
+        match read_input(&mut input) {
             Ok(_n) => {
                 let trimmed_input = input.trim();
                 let length = trimmed_input.len();
@@ -284,7 +604,7 @@ pub fn get_string(
     }
 }
 
-/// Prompts the user for a number input and returns it.
+/// Prompts the user for a number input and returns it. If the input is not a valid number of type T, the user is told why, such as that it is too large or not a whole number, and which numbers the type allows. With `set_number_parsing(NumberParsing::Extended)`, inputs such as `0xff`, `1,500`, `1.5e3` and `2k` are also accepted.
 ///
 /// # Arguments
 ///
@@ -295,7 +615,7 @@ pub fn get_string(
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use simple_cli::*;
 /// let input = get_number::<i8>(Some("Enter an integer from 0 to 10:"), None, Some(0), Some(10));
 ///
@@ -307,19 +627,18 @@ pub fn get_number<T: PartialOrd + Display + FromStr + Copy>(
     min_value: Option<T>,
     max_value: Option<T>,
 ) -> T {
+    output::begin_prompt();
     print_prompt(prompt);
     let mut input = String::new();
     loop {
-        match io::stdin().read_line(&mut input) {
-            Ok(_n) => match input.trim().parse::<T>() {
+        match read_input(&mut input) {
+            Ok(_n) => match number::parse_number::<T>(input.trim()) {
                 Ok(number) => {
                     if check_min_max(number, min_value, max_value) {
                         return number;
                     }
                 }
-                Err(_e) => {
-                    println!("Please enter a valid {} value.", type_name::<T>());
-                }
+                Err(message) => prompt_println!("{}", theme::error(message)),
             },
             Err(error) => panic!("Unexpected stdin error while reading input: {}", error),
         }
@@ -328,6 +647,87 @@ pub fn get_number<T: PartialOrd + Display + FromStr + Copy>(
     }
 }
 
+/// A function which checks a value entered by the user, returning an error message to show the user if the value is not acceptable.
+pub type Validator<'a, T> = dyn Fn(&T) -> Result<(), String> + 'a;
+
+/// Prompts the user for a value of any type that can be parsed from a string, such as an `IpAddr` or a `PathBuf`, and returns it. If the input can't be parsed, the parse error is shown and the user is asked again.
+///
+/// # Arguments
+///
+/// * `prompt` - An option that can contain a string slice which holds the prompt to present the user with.
+/// * `repeat_message` - An option that can contain a string slice which holds a repeat message which will be displayed if the user enters invalid input
+/// * `default` - An option that can contain a value which is returned if the user's input is empty.
+/// * `validator` - An option that can contain a function which checks the parsed value, returning an error message to show the user if it is not acceptable.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// use std::net::{IpAddr, Ipv4Addr};
+/// let address: IpAddr = get_parsed(
+///     Some("Enter the server's address:"),
+///     None,
+///     Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+///     Some(&|address: &IpAddr| {
+///         if address.is_unspecified() {
+///             Err(String::from("The address must not be unspecified."))
+///         } else {
+///             Ok(())
+///         }
+///     }),
+/// );
+/// ```
+pub fn get_parsed<T: FromStr>(
+    prompt: Option<&str>,
+    repeat_message: Option<&str>,
+    default: Option<T>,
+    validator: Option<&Validator<T>>,
+) -> T
+where
+    T::Err: Display,
+{
+    let mut default = default;
+    output::begin_prompt();
+    print_prompt(prompt);
+    let mut input = String::new();
+    loop {
+        match read_input(&mut input) {
+            Ok(_n) => {
+                let trimmed = input.trim();
+                if trimmed.is_empty() {
+                    if let Some(default) = default.take() {
+                        return default;
+                    }
+                }
+                match parse_and_validate(trimmed, validator) {
+                    Ok(value) => return value,
+                    Err(message) => prompt_println!("{}", theme::error(message)),
+                }
+            }
+            Err(error) => panic!("Unexpected stdin error while reading input: {}", error),
+        }
+        input.clear();
+        print_prompt(repeat_message);
+    }
+}
+
+/// Parses the input and checks it with the validator, returning the message to show the user if either fails.
+fn parse_and_validate<T: FromStr>(
+    input: &str,
+    validator: Option<&Validator<T>>,
+) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    let value = input.parse::<T>().map_err(|error| {
+        messages::message(|m| &m.parse_error, &[("input", &input), ("error", &error)])
+    })?;
+    if let Some(validator) = validator {
+        validator(&value)?;
+    }
+    Ok(value)
+}
+
 /// Prompts the user to input a number from a selection of number choices, and returns the number the user selected. Panics if there are no numbers in the vector passed into the function.
 ///
 /// # Arguments
@@ -339,7 +739,7 @@ pub fn get_number<T: PartialOrd + Display + FromStr + Copy>(
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use simple_cli::*;
 /// let choices: Vec<i8> = vec![1,2,3];
 /// let choice = select_number_from_choices::<i8>(Some("Enter 1, 2 or 3"), None, choices, true);
@@ -351,23 +751,22 @@ pub fn select_number_from_choices<T: PartialOrd + Display + FromStr + Copy>(
     choices: Vec<T>,
     show_choices_on_failure: bool,
 ) -> T {
-    if choices.len() == 0 {
+    if choices.is_empty() {
         panic!("You have not supplied a vector of at least one integer choices.")
     }
 
+    output::begin_prompt();
     print_prompt(prompt);
     let mut input = String::new();
     loop {
-        match io::stdin().read_line(&mut input) {
-            Ok(_n) => match input.trim().parse::<T>() {
+        match read_input(&mut input) {
+            Ok(_n) => match number::parse_number::<T>(input.trim()) {
                 Ok(number) => {
                     if check_number_is_a_choice(&number, &choices, show_choices_on_failure) {
                         return number;
                     }
                 }
-                Err(_e) => {
-                    println!("Please enter a valid {} value.", type_name::<T>());
-                }
+                Err(message) => prompt_println!("{}", theme::error(message)),
             },
             Err(error) => panic!("Unexpected stdin error while reading input: {}", error),
         }
@@ -388,7 +787,7 @@ pub fn select_number_from_choices<T: PartialOrd + Display + FromStr + Copy>(
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use simple_cli::*;
 /// let choices = vec!["Moe", "Larry", "Curly"];
 /// let choice = select_string_from_choices(Some("Select Moe, Larry, or Curly"), None, choices, false, true);
@@ -401,13 +800,14 @@ pub fn select_string_from_choices(
     case_sensitive: bool,
     show_choices_on_failure: bool,
 ) -> String {
-    if choices.len() == 0 {
+    if choices.is_empty() {
         panic!("You have not supplied a vector of at least one string choices.")
     }
+    output::begin_prompt();
     print_prompt(prompt);
     let mut input = String::new();
     loop {
-        match io::stdin().read_line(&mut input) {
+        match read_input(&mut input) {
             Ok(_n) => {
                 let trimmed = input.trim().to_string();
                 if check_string_is_a_choice(
@@ -426,7 +826,269 @@ pub fn select_string_from_choices(
     }
 }
 
+/// Prompts the user to select any number of strings from a checklist of string choices, and returns the selected strings in the order they appear in the choices vector. Panics if there are no strings in the choices vector passed into the function.
+///
+/// When attached to a terminal, the choices are shown as an interactive checklist: the arrow keys move the cursor, Space toggles the highlighted item, A toggles all items and Enter confirms the selection. Otherwise, the user is asked to type their selections separated by commas.
+///
+/// # Arguments
+///
+/// * `prompt` - An option that can contain a string slice which holds the prompt to present the user with.
+/// * `repeat_message` - An option that can contain a string slice which holds a repeat message which will be displayed if the user enters invalid input
+/// * `choices` - A vector of string slices which make up the choices the user can select from.
+/// * `case_sensitive` - A boolean which represents whether the user's typed input is case-sensitive.
+/// * `show_choices_on_failure` - Whether or not to show the available choices after invalid typed input.
+/// * `min_selected` - An option that can contain an integer which specifies the minimum number of items the user must select.
+/// * `max_selected` - An option that can contain an integer which specifies the maximum number of items the user can select.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let choices = vec!["Moe", "Larry", "Curly"];
+/// let selected = select_strings_from_checklist(Some("Select your favorite stooges"), None, choices, false, true, Some(1), Some(2));
+///
+/// ```
+pub fn select_strings_from_checklist(
+    prompt: Option<&str>,
+    repeat_message: Option<&str>,
+    choices: Vec<&str>,
+    case_sensitive: bool,
+    show_choices_on_failure: bool,
+    min_selected: Option<i32>,
+    max_selected: Option<i32>,
+) -> Vec<String> {
+    if choices.is_empty() {
+        panic!("You have not supplied a vector of at least one string choices.")
+    }
+    output::begin_prompt();
+    print_prompt(prompt);
+    let selected = match term::RawMode::enable() {
+        Some(raw_mode) => run_checklist(raw_mode, &choices, min_selected, max_selected),
+        None => read_checklist_input(
+            repeat_message,
+            &choices,
+            case_sensitive,
+            show_choices_on_failure,
+            min_selected,
+            max_selected,
+        ),
+    };
+    choices
+        .iter()
+        .zip(selected.iter())
+        .filter(|(_, is_selected)| **is_selected)
+        .map(|(choice, _)| choice.to_string())
+        .collect()
+}
+
+fn selection_count_error(
+    count: usize,
+    min_selected: Option<i32>,
+    max_selected: Option<i32>,
+) -> Option<String> {
+    let count = count as i32;
+    if let Some(min) = min_selected {
+        if count < min {
+            return Some(messages::message(
+                |m| &m.too_few_selected,
+                &[("count", &count), ("min", &min)],
+            ));
+        }
+    }
+    if let Some(max) = max_selected {
+        if count > max {
+            return Some(messages::message(
+                |m| &m.too_many_selected,
+                &[("count", &count), ("max", &max)],
+            ));
+        }
+    }
+    None
+}
+
+fn check_selection_count(
+    count: usize,
+    min_selected: Option<i32>,
+    max_selected: Option<i32>,
+) -> bool {
+    match selection_count_error(count, min_selected, max_selected) {
+        Some(error) => {
+            prompt_println!("{}", theme::error(error));
+            false
+        }
+        None => true,
+    }
+}
+
+fn parse_checklist_input(
+    input: &str,
+    choices: &Vec<&str>,
+    case_sensitive: bool,
+    show_choices_on_failure: bool,
+) -> Option<Vec<bool>> {
+    let mut selected = vec![false; choices.len()];
+    for token in input
+        .split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+    {
+        let token = token.to_string();
+        if !check_string_is_a_choice(&token, choices, case_sensitive, show_choices_on_failure) {
+            return None;
+        }
+        let index = choices.iter().position(|choice| {
+            *choice == token || (!case_sensitive && choice.to_lowercase() == token.to_lowercase())
+        });
+        if let Some(index) = index {
+            selected[index] = true;
+        }
+    }
+    Some(selected)
+}
+
+fn read_checklist_input(
+    repeat_message: Option<&str>,
+    choices: &Vec<&str>,
+    case_sensitive: bool,
+    show_choices_on_failure: bool,
+    min_selected: Option<i32>,
+    max_selected: Option<i32>,
+) -> Vec<bool> {
+    prompt_println!(
+        "{}",
+        messages::message(
+            |m| &m.checklist_choices,
+            &[("choices", &choices.join(", "))]
+        )
+    );
+    prompt_println!("{}", messages::message(|m| &m.checklist_prompt, &[]));
+    let mut input = String::new();
+    loop {
+        match read_input(&mut input) {
+            Ok(_n) => {
+                if let Some(selected) =
+                    parse_checklist_input(&input, choices, case_sensitive, show_choices_on_failure)
+                {
+                    let count = selected.iter().filter(|is_selected| **is_selected).count();
+                    if check_selection_count(count, min_selected, max_selected) {
+                        return selected;
+                    }
+                }
+            }
+            Err(error) => panic!("Unexpected stdin error while reading input: {}", error),
+        }
+        input.clear();
+        print_prompt(repeat_message);
+    }
+}
+
+/// Returns the index of the first choice shown when only `visible` choices fit on the screen, scrolling from `top` just far enough to keep the cursor in view.
+fn scroll_to_cursor(top: usize, cursor: usize, visible: usize) -> usize {
+    if cursor < top {
+        cursor
+    } else if cursor >= top + visible {
+        cursor + 1 - visible
+    } else {
+        top
+    }
+}
+
+/// Draws the choices of a checklist that fit in the terminal's height, scrolling `top` to keep the cursor in view. Returns the number of lines drawn.
+fn render_checklist(
+    choices: &[&str],
+    selected: &[bool],
+    cursor: usize,
+    top: &mut usize,
+    error: Option<&str>,
+) -> usize {
+    let mut output = output::PromptWriter;
+    let hint = theme::hint(messages::message(|m| &m.checklist_keys, &[]));
+    let error = error.map(theme::error);
+    let reserved_lines = count_lines(&hint) + error.as_deref().map_or(0, count_lines) + 1;
+    let visible = term::size().map_or(choices.len(), |(_, rows)| {
+        rows.saturating_sub(reserved_lines).max(1)
+    });
+    *top = scroll_to_cursor(*top, cursor, visible).min(choices.len().saturating_sub(visible));
+    let mut lines = 0;
+    for (index, choice) in choices.iter().enumerate().skip(*top).take(visible) {
+        let pointer = if index == cursor { ">" } else { " " };
+        let mark = if selected[index] { "x" } else { " " };
+        let mut line = format!("{} [{}] {}", pointer, mark, choice);
+        if selected[index] {
+            line = theme::selected(line);
+        }
+        let _ = writeln!(output, "{}", line);
+        lines += count_lines(&line);
+    }
+    let _ = writeln!(output, "{}", hint);
+    lines += count_lines(&hint);
+    if let Some(error) = error {
+        let _ = writeln!(output, "{}", error);
+        lines += count_lines(&error);
+    }
+    let _ = output.flush();
+    lines
+}
+
+fn run_checklist(
+    raw_mode: term::RawMode,
+    choices: &[&str],
+    min_selected: Option<i32>,
+    max_selected: Option<i32>,
+) -> Vec<bool> {
+    let mut selected = vec![false; choices.len()];
+    let mut cursor = 0;
+    let mut error: Option<String> = None;
+    let mut top = 0;
+    let mut lines = render_checklist(choices, &selected, cursor, &mut top, None);
+    loop {
+        match term::read_key() {
+            term::Key::Up | term::Key::Char('k') => {
+                cursor = cursor.checked_sub(1).unwrap_or(choices.len() - 1);
+                error = None;
+            }
+            term::Key::Down | term::Key::Char('j') | term::Key::Tab => {
+                cursor = (cursor + 1) % choices.len();
+                error = None;
+            }
+            term::Key::Char(' ') => {
+                let mut toggled = selected.clone();
+                toggled[cursor] = !toggled[cursor];
+                error = toggle_error(&toggled, max_selected);
+                if error.is_none() {
+                    selected = toggled;
+                }
+            }
+            term::Key::Char('a') | term::Key::Char('A') => {
+                let select_all = selected.iter().any(|is_selected| !is_selected);
+                let toggled = vec![select_all; choices.len()];
+                error = toggle_error(&toggled, max_selected);
+                if error.is_none() {
+                    selected = toggled;
+                }
+            }
+            term::Key::Enter => {
+                let count = selected.iter().filter(|is_selected| **is_selected).count();
+                error = selection_count_error(count, min_selected, max_selected);
+                if error.is_none() {
+                    return selected;
+                }
+            }
+            term::Key::CtrlC => term::interrupt(&raw_mode),
+            _ => {}
+        }
+        term::erase_lines(lines);
+        lines = render_checklist(choices, &selected, cursor, &mut top, error.as_deref());
+    }
+}
+
+fn toggle_error(selected: &[bool], max_selected: Option<i32>) -> Option<String> {
+    let count = selected.iter().filter(|is_selected| **is_selected).count();
+    selection_count_error(count, None, max_selected)
+}
+
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -449,6 +1111,31 @@ mod tests {
         assert_eq!(check_length(&big_string.len(), yes_max_length), false);
     }
 
+    #[test]
+    fn test_parse_and_validate() {
+        let not_loopback = |address: &std::net::IpAddr| {
+            if address.is_loopback() {
+                Err(String::from("Loopback"))
+            } else {
+                Ok(())
+            }
+        };
+        assert_eq!(
+            parse_and_validate::<std::net::IpAddr>("10.0.0.1", Some(&not_loopback)),
+            Ok("10.0.0.1".parse().unwrap())
+        );
+        assert_eq!(
+            parse_and_validate::<std::net::IpAddr>("127.0.0.1", Some(&not_loopback)),
+            Err(String::from("Loopback"))
+        );
+        assert_eq!(
+            with_messages(Messages::default(), || parse_and_validate::<u8>("x", None)),
+            Err(String::from(
+                "Your input (x) is not valid: invalid digit found in string"
+            ))
+        );
+    }
+
     #[test]
     fn test_check_empty() {
         let empty_string = "";
@@ -520,4 +1207,68 @@ mod tests {
             false
         );
     }
+
+    #[test]
+    fn test_check_selection_count() {
+        assert!(check_selection_count(0, None, None));
+        assert!(!check_selection_count(0, Some(1), None));
+        assert!(check_selection_count(2, Some(1), Some(2)));
+        assert!(!check_selection_count(3, Some(1), Some(2)));
+    }
+
+    #[test]
+    fn test_parse_checklist_input() {
+        let choices = vec!["Moe", "Larry", "Curly"];
+        assert_eq!(
+            parse_checklist_input("moe, Curly", &choices, false, true),
+            Some(vec![true, false, true])
+        );
+        assert_eq!(
+            parse_checklist_input("moe, Curly", &choices, true, true),
+            None
+        );
+        assert_eq!(
+            parse_checklist_input("", &choices, true, false),
+            Some(vec![false, false, false])
+        );
+        assert_eq!(parse_checklist_input("Shemp", &choices, false, false), None);
+    }
+
+    #[test]
+    fn test_filter_items() {
+        let texts = vec![
+            String::from("Hippo"),
+            String::from("Elephant"),
+            String::from("Big hippo"),
+        ];
+        assert_eq!(filter_items(&texts, None), vec![0, 1, 2]);
+        assert_eq!(filter_items(&texts, Some("hip")), vec![0, 2]);
+        assert_eq!(filter_items(&texts, Some("Hip")), vec![0]);
+        assert_eq!(filter_items(&texts, Some("zebra")), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_count_wrapped_lines() {
+        assert_eq!(count_wrapped_lines("one\ntwo", 80), 2);
+        assert_eq!(count_wrapped_lines("", 80), 1);
+        assert_eq!(count_wrapped_lines(&"x".repeat(80), 80), 1);
+        assert_eq!(count_wrapped_lines(&"x".repeat(81), 80), 2);
+        assert_eq!(count_wrapped_lines(&"x".repeat(200), 80), 3);
+        assert_eq!(display_width("\x1b[1;32mGreen\x1b[0m"), 5);
+        assert_eq!(display_width("ページ 2/5"), 10);
+        assert_eq!(count_wrapped_lines(&"ページ".repeat(10), 40), 2);
+        assert_eq!(
+            count_wrapped_lines(&format!("\x1b[7m{}\x1b[0m", "x".repeat(80)), 80),
+            1
+        );
+    }
+
+    #[test]
+    fn test_scroll_to_cursor() {
+        assert_eq!(scroll_to_cursor(0, 3, 5), 0);
+        assert_eq!(scroll_to_cursor(0, 5, 5), 1);
+        assert_eq!(scroll_to_cursor(4, 9, 5), 5);
+        assert_eq!(scroll_to_cursor(5, 2, 5), 2);
+        assert_eq!(scroll_to_cursor(5, 0, 5), 0);
+    }
 }
@@ -0,0 +1,201 @@
+use std::fmt::Display;
+
+use crate::{print_header, term};
+
+/// The marker displayed before each item of a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListMarker {
+    /// No marker.
+    #[default]
+    None,
+    /// Numbers counting up from the given start index, such as `1.`, `2.`, `3.`
+    Numbered(usize),
+    /// A bullet, such as `•` or `-`.
+    Bulleted(char),
+}
+
+/// Options for how `print_list_with` lays out a list of items.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// let items = vec!["Moe", "Larry", "Curly"];
+/// print_list_with(Some("Stooges:"), &items, ListOptions::new().numbered(1).indent(2));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    marker: ListMarker,
+    indent: usize,
+    grid: bool,
+}
+
+impl ListOptions {
+    /// Creates options for a plain list with one item per line, which is how `print_list` displays items.
+    pub fn new() -> ListOptions {
+        ListOptions::default()
+    }
+
+    /// Numbers the items, counting up from `start`.
+    pub fn numbered(&mut self, start: usize) -> &mut ListOptions {
+        self.marker = ListMarker::Numbered(start);
+        self
+    }
+
+    /// Puts a bullet before each item.
+    pub fn bulleted(&mut self, bullet: char) -> &mut ListOptions {
+        self.marker = ListMarker::Bulleted(bullet);
+        self
+    }
+
+    /// Sets the marker displayed before each item.
+    pub fn marker(&mut self, marker: ListMarker) -> &mut ListOptions {
+        self.marker = marker;
+        self
+    }
+
+    /// Indents every item by a number of spaces. The following lines of items that span multiple lines are indented further, to line up with the text after the marker.
+    pub fn indent(&mut self, spaces: usize) -> &mut ListOptions {
+        self.indent = spaces;
+        self
+    }
+
+    /// Lays the items out in as many columns as fit the terminal's width, filling each column from top to bottom like `ls`. Lists with items that span multiple lines are displayed one item per line.
+    pub fn grid(&mut self, grid: bool) -> &mut ListOptions {
+        self.grid = grid;
+        self
+    }
+}
+
+/// Returns the marker for each item, padded to the same width and followed by a space.
+fn item_markers(marker: ListMarker, item_count: usize) -> Vec<String> {
+    match marker {
+        ListMarker::None => vec![String::new(); item_count],
+        ListMarker::Numbered(start) => {
+            let width = (start + item_count.saturating_sub(1)).to_string().len();
+            (start..start + item_count)
+                .map(|number| format!("{:>width$}. ", number, width = width))
+                .collect()
+        }
+        ListMarker::Bulleted(bullet) => vec![format!("{} ", bullet); item_count],
+    }
+}
+
+/// Lays out the items of a list as lines no wider than `width` in grid mode.
+fn list_lines(texts: &[String], options: &ListOptions, width: usize) -> Vec<String> {
+    let indent = " ".repeat(options.indent);
+    let cells: Vec<String> = item_markers(options.marker, texts.len())
+        .into_iter()
+        .zip(texts)
+        .map(|(marker, text)| {
+            let continuation = format!("\n{}{}", indent, " ".repeat(marker.chars().count()));
+            format!("{}{}", marker, text.replace('\n', &continuation))
+        })
+        .collect();
+    if options.grid && !texts.iter().any(|text| text.contains('\n')) {
+        let lengths: Vec<usize> = cells.iter().map(|cell| cell.chars().count()).collect();
+        let available = width.saturating_sub(options.indent);
+        // No more columns than fit at the narrowest cell's width can fit, and as many as fit at the widest cell's width always do, so only the counts in between need to be tried.
+        let shortest = lengths.iter().copied().min().unwrap_or(0);
+        let longest = lengths.iter().copied().max().unwrap_or(0);
+        let most_columns = ((available + 2) / (shortest + 2)).min(cells.len());
+        let fewest_columns = ((available + 2) / (longest + 2)).clamp(2, most_columns.max(2));
+        for columns in (fewest_columns..=most_columns).rev() {
+            let rows = cells.len().div_ceil(columns);
+            let column_widths: Vec<usize> = lengths
+                .chunks(rows)
+                .map(|column| column.iter().copied().max().unwrap_or(0))
+                .collect();
+            if column_widths.iter().sum::<usize>() + 2 * (column_widths.len() - 1) > available {
+                continue;
+            }
+            return (0..rows)
+                .map(|row| {
+                    let line: Vec<String> = (row..cells.len())
+                        .step_by(rows)
+                        .zip(&column_widths)
+                        .map(|(i, &column_width)| {
+                            format!("{:<width$}", cells[i], width = column_width)
+                        })
+                        .collect();
+                    format!("{}{}", indent, line.join("  ").trim_end())
+                })
+                .collect();
+        }
+    }
+    cells
+        .into_iter()
+        .map(|cell| format!("{}{}", indent, cell))
+        .collect()
+}
+
+/// Displays a list of items, numbered, bulleted, indented or laid out in a grid as set in the options.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the list.
+/// * `items` - An array of items of a type with 'Display' trait
+/// * `options` - The options for how the list is laid out.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// let files = vec!["Cargo.toml", "README.md", "src", "target", "tests", "examples"];
+/// print_list_with(None, &files, ListOptions::new().grid(true));
+/// print_list_with(Some("Steps:"), &files, ListOptions::new().bulleted('-'));
+/// ```
+pub fn print_list_with<T: Display>(
+    header_message: Option<&str>,
+    items: &[T],
+    options: &ListOptions,
+) {
+    print_header(header_message);
+    let texts: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+    for line in list_lines(&texts, options, term::width()) {
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[test]
+    fn test_list_markers_and_indent() {
+        let items = texts(&["Moe", "Larry\nCurly"]);
+        assert_eq!(
+            list_lines(&items, ListOptions::new().numbered(9).indent(2), 80),
+            vec!["   9. Moe", "  10. Larry\n      Curly"]
+        );
+        assert_eq!(
+            list_lines(&items, ListOptions::new().bulleted('-'), 80),
+            vec!["- Moe", "- Larry\n  Curly"]
+        );
+    }
+
+    #[test]
+    fn test_list_grid() {
+        let items = texts(&["a", "bb", "ccc", "d", "eeeee"]);
+        assert_eq!(
+            list_lines(&items, ListOptions::new().grid(true), 80),
+            vec!["a  bb  ccc  d  eeeee"]
+        );
+        assert_eq!(
+            list_lines(&items, ListOptions::new().grid(true), 14),
+            vec!["a   ccc  eeeee", "bb  d"]
+        );
+        assert_eq!(
+            list_lines(&items, ListOptions::new().grid(true), 3),
+            texts(&["a", "bb", "ccc", "d", "eeeee"])
+        );
+        let items = vec![String::from("x"); 100_000];
+        let lines = list_lines(&items, ListOptions::new().grid(true), 80);
+        assert_eq!(lines.len(), 100_000_usize.div_ceil(27));
+        assert_eq!(lines[0].len(), 79);
+    }
+}
@@ -0,0 +1,389 @@
+use std::str::FromStr;
+
+use crate::Messages;
+
+/// A language the library's messages are translated to. Pass one to `set_locale` or `Messages::for_locale` to use its bundled translations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// English, which is used until another locale is set.
+    #[default]
+    English,
+    /// German (`de`).
+    German,
+    /// Spanish (`es`).
+    Spanish,
+    /// French (`fr`).
+    French,
+    /// Japanese (`ja`).
+    Japanese,
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// Parses a language tag or POSIX locale name, such as `de`, `fr-CA` or `ja_JP.UTF-8`. The `C` and `POSIX` locales are English.
+    fn from_str(s: &str) -> Result<Locale, String> {
+        let language = s
+            .trim()
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default();
+        match language.to_lowercase().as_str() {
+            "en" | "c" | "posix" => Ok(Locale::English),
+            "de" => Ok(Locale::German),
+            "es" => Ok(Locale::Spanish),
+            "fr" => Ok(Locale::French),
+            "ja" => Ok(Locale::Japanese),
+            _ => Err(format!("Unsupported locale: {}", s)),
+        }
+    }
+}
+
+impl Locale {
+    /// Returns the locale named by the first of the `LC_ALL`, `LC_MESSAGES` and `LANG` environment variables that is set, or None if it is not one of the bundled translations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use simple_cli::*;
+    /// let locale = Locale::from_env().unwrap_or(Locale::English);
+    /// ```
+    pub fn from_env() -> Option<Locale> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())?
+            .parse()
+            .ok()
+    }
+
+    /// Returns the character that separates the whole and fractional parts of a number, such as the comma in `3,5`.
+    pub(crate) fn decimal_separator(&self) -> char {
+        match self {
+            Locale::English | Locale::Japanese => '.',
+            Locale::German | Locale::Spanish | Locale::French => ',',
+        }
+    }
+
+    /// Returns the characters that separate groups of thousands in a number, such as the commas in `1,000,000`.
+    pub(crate) fn thousands_separators(&self) -> &'static [char] {
+        match self {
+            Locale::English | Locale::Japanese => &[','],
+            Locale::German | Locale::Spanish => &['.'],
+            Locale::French => &[' ', '\u{a0}', '\u{202f}'],
+        }
+    }
+
+    /// Returns the index of the plural form used for a number, where 0 is the singular form and 1 the plural form. Japanese has a single form.
+    pub(crate) fn plural_form(&self, number: f64) -> usize {
+        let number = number.abs();
+        match self {
+            Locale::English | Locale::German | Locale::Spanish => usize::from(number != 1.0),
+            Locale::French => usize::from(number >= 2.0),
+            Locale::Japanese => 0,
+        }
+    }
+}
+
+pub(crate) fn german() -> Messages {
+    Messages {
+        locale: Locale::German,
+        input_too_long: String::from("Ihre Eingabe ist {over} Zeichen länger als die Grenze von {limit} Zeichen. Bitte versuchen Sie es erneut."),
+        input_empty: String::from("Ihre Eingabe darf nicht leer sein."),
+        below_min: String::from("Ihre Eingabe ({input}) ist kleiner als der kleinste erlaubte Wert {min}."),
+        above_max: String::from("Ihre Eingabe ({input}) ist größer als der größte erlaubte Wert {max}."),
+        invalid_value: String::from("{cause} Bitte geben Sie {description} ein."),
+        parse_error: String::from("Ihre Eingabe ({input}) ist ungültig: {error}"),
+        whole_number: String::from("eine ganze Zahl zwischen {min} und {max}"),
+        decimal_number: String::from("eine Dezimalzahl"),
+        any_number: String::from("eine Zahl"),
+        not_a_number: String::from("Ihre Eingabe ({input}) ist keine Zahl."),
+        not_a_whole_number: String::from("Ihre Eingabe ({input}) ist keine ganze Zahl."),
+        number_too_large: String::from("Ihre Eingabe ({input}) ist zu groß."),
+        number_too_small: String::from("Ihre Eingabe ({input}) ist zu klein."),
+        invalid_choice: String::from("Ihre Eingabe ({input}) ist keine gültige Auswahl."),
+        invalid_choice_with_choices: String::from("Ihre Eingabe ({input}) ist keine der Auswahlmöglichkeiten: {choices}"),
+        case_sensitive: String::from("(Groß- und Kleinschreibung wird beachtet)"),
+        case_insensitive: String::from("(Groß- und Kleinschreibung wird nicht beachtet)"),
+        checklist_choices: String::from("Auswahlmöglichkeiten: {choices}"),
+        checklist_prompt: String::from("Geben Sie Ihre Auswahl durch Kommas getrennt ein."),
+        checklist_keys: String::from("(Leertaste zum Umschalten, A schaltet alle um, Enter zum Bestätigen)"),
+        too_few_selected: String::from("Sie haben {count} {count|Element|Elemente} ausgewählt, es müssen aber mindestens {min} ausgewählt werden."),
+        too_many_selected: String::from("Sie haben {count} {count|Element|Elemente} ausgewählt, es können aber höchstens {max} ausgewählt werden."),
+        page_prompt: String::from("Geben Sie die Seite ein, die Sie ansehen möchten."),
+        page_footer: String::from("(Seite {page} von {pages})"),
+        filtered_page_footer: String::from("(Seite {page} von {pages}, {matches} von {total} {total|Element|Elementen} passen zu \"{filter}\")"),
+        list_keys: String::from("[N] weiter  [P] zurück  [Pos1/Ende] erste/letzte  [#] Seite  [/] suchen  [C] zurücksetzen  [Q] beenden"),
+        list_help: String::from("Drücken Sie N für die nächste Seite, P für die vorherige, F für die erste, L für die letzte, S oder eine Seitenzahl für eine bestimmte Seite, / zum Suchen, C zum Zurücksetzen der Suche oder E zum Beenden."),
+        select_keys: String::from("[#] auswählen  [Enter] Namen eingeben  [N] weiter  [P] zurück  [S] Seite  [/] suchen  [Q] beenden"),
+        select_help: String::from("Geben Sie die Nummer oder den Namen eines Elements ein, um es auszuwählen, oder drücken Sie N für die nächste Seite, P für die vorherige, F für die erste, L für die letzte, S für eine bestimmte Seite, / zum Suchen, C zum Zurücksetzen der Suche oder E zum Beenden."),
+        list_search_prompt: String::from("Geben Sie einen Suchbegriff ein, um die Liste zu filtern, oder lassen Sie die Eingabe leer, um die Suche zurückzusetzen."),
+        inline_search: String::from("Suche: "),
+        inline_page: String::from("Gehe zu Seite: "),
+        inline_line: String::from("Gehe zu Zeile: "),
+        inline_sort: String::from("Sortieren nach Spalte: "),
+        inline_command: String::from("Befehl: "),
+        source_keys: String::from("[N] weiter  [P] zurück  [Pos1/Ende] erste/letzte  [#] Seite  [Q] beenden"),
+        source_help: String::from("Drücken Sie N für die nächste Seite, P für die vorherige, F für die erste, L für die letzte, S oder eine Seitenzahl für eine bestimmte Seite oder E zum Beenden."),
+        table_page_footer: String::from("(Seite {page} von {pages}, {details})"),
+        table_filter_status: String::from("{matches} von {total} {total|Zeile|Zeilen} passen zu \"{filter}\""),
+        table_sorted_ascending: String::from("aufsteigend sortiert nach {column}"),
+        table_sorted_descending: String::from("absteigend sortiert nach {column}"),
+        table_keys: String::from("[N] weiter  [P] zurück  [#] Seite  [O] sortieren  [/] suchen  [C] zurücksetzen  [Q] beenden"),
+        table_help: String::from("Drücken Sie N für die nächste Seite, P für die vorherige, F für die erste, L für die letzte, S oder eine Seitenzahl für eine bestimmte Seite, O und eine Spalte, um danach zu sortieren, / zum Suchen oder /Spalte=Wert, um eine Spalte zu filtern, C zum Zurücksetzen der Suche oder E zum Beenden."),
+        table_search_prompt: String::from("Geben Sie einen Suchbegriff oder Spalte=Wert ein, um eine Spalte zu filtern, oder lassen Sie die Eingabe leer, um die Suche zurückzusetzen."),
+        unknown_column: String::from("Es gibt keine Spalte namens \"{input}\"."),
+        text_page_footer: String::from("(Seite {page} von {pages}, Zeilen {first}-{last} von {total})"),
+        text_keys: String::from("[N] weiter  [P] zurück  [Pos1/Ende] erste/letzte  [/] suchen  [:] Zeile  [Q] beenden"),
+        text_help: String::from("Drücken Sie N für die nächste Seite, P für die vorherige, F für die erste, L für die letzte, S oder eine Seitenzahl für eine bestimmte Seite, / gefolgt von einem Begriff zum Suchen, : gefolgt von einer Zahl, um zu einer Zeile zu springen, oder E zum Beenden."),
+        text_search_prompt: String::from("Geben Sie einen Suchbegriff ein, oder lassen Sie die Eingabe leer, um die letzte Suche zu wiederholen."),
+        no_such_line: String::from("Es gibt keine Zeile {input}."),
+        no_matching_lines: String::from("Keine Zeile enthält \"{input}\"."),
+        follow_following: String::from("Folgt der Ausgabe"),
+        follow_paused: String::from("Angehalten"),
+        follow_paused_new_lines: String::from("Angehalten, {count} neue {count|Zeile|Zeilen}"),
+        follow_footer: String::from("({state}, Zeilen {first}-{last} von {total})"),
+        follow_footer_finished: String::from("({state}, Zeilen {first}-{last} von {total}, Ende der Ausgabe)"),
+        follow_keys: String::from("[Hoch/Runter] blättern  [F/Ende] folgen  [Q] beenden"),
+        follow_read_error: String::from("Fehler beim Lesen: {error}"),
+        tree_keys: String::from("[#] auf-/zuklappen  [N] weiter  [P] zurück  [S] Seite  [Q] beenden"),
+        tree_help: String::from("Geben Sie die Nummer oder Bezeichnung eines Knotens ein, um ihn auf- oder zuzuklappen, oder drücken Sie N für die nächste Seite, P für die vorherige, F für die erste, L für die letzte, S für eine bestimmte Seite oder E zum Beenden."),
+        fuzzy_prompt: String::from("Geben Sie einen Suchbegriff ein, oder lassen Sie die Eingabe leer, um abzubrechen."),
+        fuzzy_keys: String::from("({matches} von {total} {total|Element|Elementen} passen. Hoch/Runter zum Bewegen, Enter zum Auswählen, Esc zum Abbrechen)"),
+        fuzzy_matches: String::from("({matches} von {total} {total|Element|Elementen} passen. Geben Sie die Nummer Ihrer Wahl oder einen neuen Suchbegriff ein.)"),
+        fuzzy_no_matches: String::from("Kein Element passt zu \"{input}\". Geben Sie einen anderen Suchbegriff ein."),
+    }
+}
+
+pub(crate) fn spanish() -> Messages {
+    Messages {
+        locale: Locale::Spanish,
+        input_too_long: String::from("Su entrada supera en {over} {over|carácter|caracteres} el límite de {limit} caracteres. Inténtelo de nuevo."),
+        input_empty: String::from("Su entrada no puede estar vacía."),
+        below_min: String::from("Su entrada ({input}) es menor que el valor mínimo permitido de {min}."),
+        above_max: String::from("Su entrada ({input}) es mayor que el valor máximo permitido de {max}."),
+        invalid_value: String::from("{cause} Introduzca {description}."),
+        parse_error: String::from("Su entrada ({input}) no es válida: {error}"),
+        whole_number: String::from("un número entero entre {min} y {max}"),
+        decimal_number: String::from("un número decimal"),
+        any_number: String::from("un número"),
+        not_a_number: String::from("Su entrada ({input}) no es un número."),
+        not_a_whole_number: String::from("Su entrada ({input}) no es un número entero."),
+        number_too_large: String::from("Su entrada ({input}) es demasiado grande."),
+        number_too_small: String::from("Su entrada ({input}) es demasiado pequeña."),
+        invalid_choice: String::from("Su entrada ({input}) no es una opción válida."),
+        invalid_choice_with_choices: String::from("Su entrada ({input}) no es una de las opciones: {choices}"),
+        case_sensitive: String::from("(Distingue mayúsculas y minúsculas)"),
+        case_insensitive: String::from("(No distingue mayúsculas y minúsculas)"),
+        checklist_choices: String::from("Opciones: {choices}"),
+        checklist_prompt: String::from("Introduzca sus selecciones separadas por comas."),
+        checklist_keys: String::from("(Espacio para marcar, A para marcar todo, Enter para confirmar)"),
+        too_few_selected: String::from("Ha seleccionado {count} {count|elemento|elementos}, pero debe seleccionar al menos {min}."),
+        too_many_selected: String::from("Ha seleccionado {count} {count|elemento|elementos}, pero puede seleccionar como máximo {max}."),
+        page_prompt: String::from("Introduzca la página que desea ver."),
+        page_footer: String::from("(Página {page} de {pages})"),
+        filtered_page_footer: String::from("(Página {page} de {pages}, {matches} de {total} {total|elemento|elementos} coinciden con \"{filter}\")"),
+        list_keys: String::from("[N] siguiente  [P] anterior  [Inicio/Fin] primera/última  [#] página  [/] buscar  [C] limpiar  [Q] salir"),
+        list_help: String::from("Pulse N para ver la página siguiente, P para la anterior, F para la primera, L para la última, S o un número de página para una página concreta, / para buscar, C para limpiar la búsqueda o E para salir."),
+        select_keys: String::from("[#] seleccionar  [Enter] escribir un nombre  [N] siguiente  [P] anterior  [S] página  [/] buscar  [Q] salir"),
+        select_help: String::from("Introduzca el número o el nombre de un elemento para seleccionarlo, o pulse N para ver la página siguiente, P para la anterior, F para la primera, L para la última, S para una página concreta, / para buscar, C para limpiar la búsqueda o E para salir."),
+        list_search_prompt: String::from("Introduzca un término para filtrar la lista, o déjelo vacío para limpiar la búsqueda."),
+        inline_search: String::from("Buscar: "),
+        inline_page: String::from("Ir a la página: "),
+        inline_line: String::from("Ir a la línea: "),
+        inline_sort: String::from("Ordenar por columna: "),
+        inline_command: String::from("Comando: "),
+        source_keys: String::from("[N] siguiente  [P] anterior  [Inicio/Fin] primera/última  [#] página  [Q] salir"),
+        source_help: String::from("Pulse N para ver la página siguiente, P para la anterior, F para la primera, L para la última, S o un número de página para una página concreta, o E para salir."),
+        table_page_footer: String::from("(Página {page} de {pages}, {details})"),
+        table_filter_status: String::from("{matches} de {total} {total|fila|filas} coinciden con \"{filter}\""),
+        table_sorted_ascending: String::from("ordenado por {column} ascendente"),
+        table_sorted_descending: String::from("ordenado por {column} descendente"),
+        table_keys: String::from("[N] siguiente  [P] anterior  [#] página  [O] ordenar  [/] buscar  [C] limpiar  [Q] salir"),
+        table_help: String::from("Pulse N para ver la página siguiente, P para la anterior, F para la primera, L para la última, S o un número de página para una página concreta, O y una columna para ordenar por ella, / para buscar o /columna=valor para filtrar una columna, C para limpiar la búsqueda o E para salir."),
+        table_search_prompt: String::from("Introduzca un término de búsqueda, o columna=valor para filtrar una columna, o déjelo vacío para limpiar la búsqueda."),
+        unknown_column: String::from("No hay ninguna columna llamada \"{input}\"."),
+        text_page_footer: String::from("(Página {page} de {pages}, líneas {first}-{last} de {total})"),
+        text_keys: String::from("[N] siguiente  [P] anterior  [Inicio/Fin] primera/última  [/] buscar  [:] línea  [Q] salir"),
+        text_help: String::from("Pulse N para ver la página siguiente, P para la anterior, F para la primera, L para la última, S o un número de página para una página concreta, / seguido de un término para buscar, : seguido de un número para ir a una línea, o E para salir."),
+        text_search_prompt: String::from("Introduzca un término de búsqueda, o déjelo vacío para repetir la última búsqueda."),
+        no_such_line: String::from("No existe la línea {input}."),
+        no_matching_lines: String::from("Ninguna línea contiene \"{input}\"."),
+        follow_following: String::from("Siguiendo"),
+        follow_paused: String::from("En pausa"),
+        follow_paused_new_lines: String::from("En pausa, {count} {count|línea nueva|líneas nuevas}"),
+        follow_footer: String::from("({state}, líneas {first}-{last} de {total})"),
+        follow_footer_finished: String::from("({state}, líneas {first}-{last} de {total}, fin de la salida)"),
+        follow_keys: String::from("[Arriba/Abajo] desplazar  [F/Fin] seguir  [Q] salir"),
+        follow_read_error: String::from("Error al leer: {error}"),
+        tree_keys: String::from("[#] expandir/contraer  [N] siguiente  [P] anterior  [S] página  [Q] salir"),
+        tree_help: String::from("Introduzca el número o la etiqueta de un nodo para expandirlo o contraerlo, o pulse N para ver la página siguiente, P para la anterior, F para la primera, L para la última, S para una página concreta o E para salir."),
+        fuzzy_prompt: String::from("Introduzca un término de búsqueda, o déjelo vacío para cancelar."),
+        fuzzy_keys: String::from("({matches} de {total} {total|elemento|elementos} coinciden. Arriba/Abajo para moverse, Enter para seleccionar, Esc para cancelar)"),
+        fuzzy_matches: String::from("({matches} de {total} {total|elemento|elementos} coinciden. Introduzca el número de su elección o un nuevo término de búsqueda.)"),
+        fuzzy_no_matches: String::from("Ningún elemento coincide con \"{input}\". Introduzca otro término de búsqueda."),
+    }
+}
+
+pub(crate) fn french() -> Messages {
+    Messages {
+        locale: Locale::French,
+        input_too_long: String::from("Votre saisie dépasse de {over} {over|caractère|caractères} la limite de {limit} caractères. Veuillez réessayer."),
+        input_empty: String::from("Votre saisie ne peut pas être vide."),
+        below_min: String::from("Votre saisie ({input}) est inférieure à la valeur minimale autorisée de {min}."),
+        above_max: String::from("Votre saisie ({input}) est supérieure à la valeur maximale autorisée de {max}."),
+        invalid_value: String::from("{cause} Veuillez saisir {description}."),
+        parse_error: String::from("Votre saisie ({input}) n'est pas valide : {error}"),
+        whole_number: String::from("un nombre entier entre {min} et {max}"),
+        decimal_number: String::from("un nombre décimal"),
+        any_number: String::from("un nombre"),
+        not_a_number: String::from("Votre saisie ({input}) n'est pas un nombre."),
+        not_a_whole_number: String::from("Votre saisie ({input}) n'est pas un nombre entier."),
+        number_too_large: String::from("Votre saisie ({input}) est trop grande."),
+        number_too_small: String::from("Votre saisie ({input}) est trop petite."),
+        invalid_choice: String::from("Votre saisie ({input}) n'est pas un choix valide."),
+        invalid_choice_with_choices: String::from("Votre saisie ({input}) ne fait pas partie des choix : {choices}"),
+        case_sensitive: String::from("(Sensible à la casse)"),
+        case_insensitive: String::from("(Insensible à la casse)"),
+        checklist_choices: String::from("Choix : {choices}"),
+        checklist_prompt: String::from("Saisissez vos sélections séparées par des virgules."),
+        checklist_keys: String::from("(Espace pour cocher, A pour tout cocher, Entrée pour confirmer)"),
+        too_few_selected: String::from("Vous avez sélectionné {count} {count|élément|éléments}, mais au moins {min} doivent être sélectionnés."),
+        too_many_selected: String::from("Vous avez sélectionné {count} {count|élément|éléments}, mais au plus {max} peuvent être sélectionnés."),
+        page_prompt: String::from("Saisissez la page que vous souhaitez afficher."),
+        page_footer: String::from("(Page {page} sur {pages})"),
+        filtered_page_footer: String::from("(Page {page} sur {pages}, {matches} {matches|élément|éléments} sur {total} {matches|correspond|correspondent} à « {filter} »)"),
+        list_keys: String::from("[N] suivante  [P] précédente  [Début/Fin] première/dernière  [#] page  [/] rechercher  [C] effacer  [Q] quitter"),
+        list_help: String::from("Appuyez sur N pour afficher la page suivante, P pour la précédente, F pour la première, L pour la dernière, S ou un numéro de page pour une page précise, / pour rechercher, C pour effacer la recherche ou E pour quitter."),
+        select_keys: String::from("[#] sélectionner  [Entrée] saisir un nom  [N] suivante  [P] précédente  [S] page  [/] rechercher  [Q] quitter"),
+        select_help: String::from("Saisissez le numéro ou le nom d'un élément pour le sélectionner, ou appuyez sur N pour afficher la page suivante, P pour la précédente, F pour la première, L pour la dernière, S pour une page précise, / pour rechercher, C pour effacer la recherche ou E pour quitter."),
+        list_search_prompt: String::from("Saisissez un terme pour filtrer la liste, ou laissez vide pour effacer la recherche."),
+        inline_search: String::from("Rechercher : "),
+        inline_page: String::from("Aller à la page : "),
+        inline_line: String::from("Aller à la ligne : "),
+        inline_sort: String::from("Trier par colonne : "),
+        inline_command: String::from("Commande : "),
+        source_keys: String::from("[N] suivante  [P] précédente  [Début/Fin] première/dernière  [#] page  [Q] quitter"),
+        source_help: String::from("Appuyez sur N pour afficher la page suivante, P pour la précédente, F pour la première, L pour la dernière, S ou un numéro de page pour une page précise, ou E pour quitter."),
+        table_page_footer: String::from("(Page {page} sur {pages}, {details})"),
+        table_filter_status: String::from("{matches} {matches|ligne|lignes} sur {total} {matches|correspond|correspondent} à « {filter} »"),
+        table_sorted_ascending: String::from("tri par {column} croissant"),
+        table_sorted_descending: String::from("tri par {column} décroissant"),
+        table_keys: String::from("[N] suivante  [P] précédente  [#] page  [O] trier  [/] rechercher  [C] effacer  [Q] quitter"),
+        table_help: String::from("Appuyez sur N pour afficher la page suivante, P pour la précédente, F pour la première, L pour la dernière, S ou un numéro de page pour une page précise, O et une colonne pour trier selon celle-ci, / pour rechercher ou /colonne=valeur pour filtrer une colonne, C pour effacer la recherche ou E pour quitter."),
+        table_search_prompt: String::from("Saisissez un terme de recherche, ou colonne=valeur pour filtrer une colonne, ou laissez vide pour effacer la recherche."),
+        unknown_column: String::from("Il n'y a pas de colonne nommée « {input} »."),
+        text_page_footer: String::from("(Page {page} sur {pages}, lignes {first}-{last} sur {total})"),
+        text_keys: String::from("[N] suivante  [P] précédente  [Début/Fin] première/dernière  [/] rechercher  [:] ligne  [Q] quitter"),
+        text_help: String::from("Appuyez sur N pour afficher la page suivante, P pour la précédente, F pour la première, L pour la dernière, S ou un numéro de page pour une page précise, / suivi d'un terme pour rechercher, : suivi d'un numéro pour aller à une ligne, ou E pour quitter."),
+        text_search_prompt: String::from("Saisissez un terme de recherche, ou laissez vide pour répéter la dernière recherche."),
+        no_such_line: String::from("La ligne {input} n'existe pas."),
+        no_matching_lines: String::from("Aucune ligne ne contient « {input} »."),
+        follow_following: String::from("Suivi en direct"),
+        follow_paused: String::from("En pause"),
+        follow_paused_new_lines: String::from("En pause, {count} {count|nouvelle ligne|nouvelles lignes}"),
+        follow_footer: String::from("({state}, lignes {first}-{last} sur {total})"),
+        follow_footer_finished: String::from("({state}, lignes {first}-{last} sur {total}, fin de la sortie)"),
+        follow_keys: String::from("[Haut/Bas] défiler  [F/Fin] suivre  [Q] quitter"),
+        follow_read_error: String::from("Erreur de lecture : {error}"),
+        tree_keys: String::from("[#] déplier/replier  [N] suivante  [P] précédente  [S] page  [Q] quitter"),
+        tree_help: String::from("Saisissez le numéro ou le libellé d'un nœud pour le déplier ou le replier, ou appuyez sur N pour afficher la page suivante, P pour la précédente, F pour la première, L pour la dernière, S pour une page précise ou E pour quitter."),
+        fuzzy_prompt: String::from("Saisissez un terme de recherche, ou laissez vide pour annuler."),
+        fuzzy_keys: String::from("({matches} {matches|élément|éléments} sur {total} {matches|correspond|correspondent}. Haut/Bas pour se déplacer, Entrée pour sélectionner, Échap pour annuler)"),
+        fuzzy_matches: String::from("({matches} {matches|élément|éléments} sur {total} {matches|correspond|correspondent}. Saisissez le numéro de votre choix ou un nouveau terme de recherche.)"),
+        fuzzy_no_matches: String::from("Aucun élément ne correspond à « {input} ». Saisissez un autre terme de recherche."),
+    }
+}
+
+pub(crate) fn japanese() -> Messages {
+    Messages {
+        locale: Locale::Japanese,
+        input_too_long: String::from("入力が{limit}文字の制限を{over}文字超えています。もう一度入力してください。"),
+        input_empty: String::from("入力を空にすることはできません。"),
+        below_min: String::from("入力値 ({input}) が最小値 {min} より小さいです。"),
+        above_max: String::from("入力値 ({input}) が最大値 {max} より大きいです。"),
+        invalid_value: String::from("{cause}{description}を入力してください。"),
+        parse_error: String::from("入力 ({input}) は無効です: {error}"),
+        whole_number: String::from("{min}から{max}までの整数"),
+        decimal_number: String::from("小数"),
+        any_number: String::from("数値"),
+        not_a_number: String::from("入力 ({input}) は数値ではありません。"),
+        not_a_whole_number: String::from("入力 ({input}) は整数ではありません。"),
+        number_too_large: String::from("入力 ({input}) が大きすぎます。"),
+        number_too_small: String::from("入力 ({input}) が小さすぎます。"),
+        invalid_choice: String::from("入力 ({input}) は有効な選択肢ではありません。"),
+        invalid_choice_with_choices: String::from("入力 ({input}) は選択肢にありません: {choices}"),
+        case_sensitive: String::from("(大文字と小文字を区別します)"),
+        case_insensitive: String::from("(大文字と小文字を区別しません)"),
+        checklist_choices: String::from("選択肢: {choices}"),
+        checklist_prompt: String::from("選択する項目をカンマ区切りで入力してください。"),
+        checklist_keys: String::from("(Space で切り替え、A ですべて切り替え、Enter で確定)"),
+        too_few_selected: String::from("{count}個の項目が選択されていますが、少なくとも{min}個選択する必要があります。"),
+        too_many_selected: String::from("{count}個の項目が選択されていますが、選択できるのは最大{max}個です。"),
+        page_prompt: String::from("表示するページを入力してください。"),
+        page_footer: String::from("(ページ {page}/{pages})"),
+        filtered_page_footer: String::from("(ページ {page}/{pages}、{total}件中{matches}件が「{filter}」に一致)"),
+        list_keys: String::from("[N] 次へ  [P] 前へ  [Home/End] 最初/最後  [#] ページ  [/] 検索  [C] クリア  [Q] 終了"),
+        list_help: String::from("N で次のページ、P で前のページ、F で最初のページ、L で最後のページ、S またはページ番号で指定したページを表示します。/ で検索、C で検索をクリア、E で終了します。"),
+        select_keys: String::from("[#] 選択  [Enter] 名前を入力  [N] 次へ  [P] 前へ  [S] ページ  [/] 検索  [Q] 終了"),
+        select_help: String::from("項目の番号または名前を入力して選択するか、N で次のページ、P で前のページ、F で最初のページ、L で最後のページ、S で指定したページを表示します。/ で検索、C で検索をクリア、E で終了します。"),
+        list_search_prompt: String::from("リストを絞り込む検索語を入力してください。空のままにすると検索をクリアします。"),
+        inline_search: String::from("検索: "),
+        inline_page: String::from("移動先のページ: "),
+        inline_line: String::from("移動先の行: "),
+        inline_sort: String::from("並べ替える列: "),
+        inline_command: String::from("コマンド: "),
+        source_keys: String::from("[N] 次へ  [P] 前へ  [Home/End] 最初/最後  [#] ページ  [Q] 終了"),
+        source_help: String::from("N で次のページ、P で前のページ、F で最初のページ、L で最後のページ、S またはページ番号で指定したページを表示します。E で終了します。"),
+        table_page_footer: String::from("(ページ {page}/{pages}、{details})"),
+        table_filter_status: String::from("{total}行中{matches}行が「{filter}」に一致"),
+        table_sorted_ascending: String::from("{column}の昇順"),
+        table_sorted_descending: String::from("{column}の降順"),
+        table_keys: String::from("[N] 次へ  [P] 前へ  [#] ページ  [O] 並べ替え  [/] 検索  [C] クリア  [Q] 終了"),
+        table_help: String::from("N で次のページ、P で前のページ、F で最初のページ、L で最後のページ、S またはページ番号で指定したページを表示します。O と列名でその列で並べ替え、/ で検索、/列=値 で列を絞り込み、C で検索をクリア、E で終了します。"),
+        table_search_prompt: String::from("検索語、または列を絞り込む 列=値 を入力してください。空のままにすると検索をクリアします。"),
+        unknown_column: String::from("「{input}」という列はありません。"),
+        text_page_footer: String::from("(ページ {page}/{pages}、{total}行中 {first}-{last}行)"),
+        text_keys: String::from("[N] 次へ  [P] 前へ  [Home/End] 最初/最後  [/] 検索  [:] 行  [Q] 終了"),
+        text_help: String::from("N で次のページ、P で前のページ、F で最初のページ、L で最後のページ、S またはページ番号で指定したページを表示します。/ に続けて検索語で検索、: に続けて番号でその行へ移動、E で終了します。"),
+        text_search_prompt: String::from("検索語を入力してください。空のままにすると前回の検索を繰り返します。"),
+        no_such_line: String::from("{input}行目はありません。"),
+        no_matching_lines: String::from("「{input}」を含む行はありません。"),
+        follow_following: String::from("追従中"),
+        follow_paused: String::from("一時停止"),
+        follow_paused_new_lines: String::from("一時停止、新しい行 {count}行"),
+        follow_footer: String::from("({state}、{total}行中 {first}-{last}行)"),
+        follow_footer_finished: String::from("({state}、{total}行中 {first}-{last}行、出力終了)"),
+        follow_keys: String::from("[Up/Down] スクロール  [F/End] 追従  [Q] 終了"),
+        follow_read_error: String::from("読み込みエラー: {error}"),
+        tree_keys: String::from("[#] 展開/折りたたみ  [N] 次へ  [P] 前へ  [S] ページ  [Q] 終了"),
+        tree_help: String::from("ノードの番号またはラベルを入力して展開または折りたたむか、N で次のページ、P で前のページ、F で最初のページ、L で最後のページ、S で指定したページを表示します。E で終了します。"),
+        fuzzy_prompt: String::from("検索語を入力してください。空のままにするとキャンセルします。"),
+        fuzzy_keys: String::from("({total}件中{matches}件が一致。Up/Down で移動、Enter で選択、Esc でキャンセル)"),
+        fuzzy_matches: String::from("({total}件中{matches}件が一致。選択する番号か、新しい検索語を入力してください。)"),
+        fuzzy_no_matches: String::from("「{input}」に一致する項目はありません。別の検索語を入力してください。"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!("de_DE.UTF-8".parse(), Ok(Locale::German));
+        assert_eq!("fr-CA".parse(), Ok(Locale::French));
+        assert_eq!("ja".parse(), Ok(Locale::Japanese));
+        assert_eq!("C".parse(), Ok(Locale::English));
+        assert!("pt_BR".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn test_plural_form() {
+        assert_eq!(Locale::English.plural_form(1.0), 0);
+        assert_eq!(Locale::English.plural_form(0.0), 1);
+        assert_eq!(Locale::French.plural_form(0.0), 0);
+        assert_eq!(Locale::French.plural_form(2.0), 1);
+        assert_eq!(Locale::Japanese.plural_form(5.0), 0);
+    }
+}
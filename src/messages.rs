@@ -0,0 +1,449 @@
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    sync::{LazyLock, Mutex},
+};
+
+use crate::locale::{self, Locale};
+
+/// The templates for every message the library displays, such as validation errors, pager footers and key hints. Placeholders in braces, such as `{input}` or `{max}`, are replaced with their values when the message is displayed, and placeholders a message does not know are left as they are.
+///
+/// A placeholder followed by forms separated by `|`, such as `{count|item|items}`, is replaced by the form the plural rules of the messages' locale pick for its value: the singular and plural forms in English, German and Spanish, where French also uses the singular for 0, and the single form in Japanese.
+///
+/// Set the messages used by every prompt with `set_messages` or `set_locale`, or by a single prompt with `with_messages`. Until then, the English messages are used. To follow the user's language instead, pass `Locale::from_env()` to `set_locale`.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// set_messages(Messages {
+///     input_empty: String::from("Please type something."),
+///     above_max: String::from("{input} is too big, the most you can enter is {max}."),
+///     ..Messages::default()
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Messages {
+    /// The language of the messages, whose plural rules pick between the forms of placeholders like `{count|item|items}`.
+    pub locale: Locale,
+    /// Shown when the input is longer than the maximum length. Placeholders: `{length}`, `{over}` (the number of characters over the limit) and `{limit}`.
+    pub input_too_long: String,
+    /// Shown when the input is empty but an empty answer is not allowed.
+    pub input_empty: String,
+    /// Shown when a number is below the minimum value. Placeholders: `{input}` and `{min}`.
+    pub below_min: String,
+    /// Shown when a number is above the maximum value. Placeholders: `{input}` and `{max}`.
+    pub above_max: String,
+    /// Shown when the input cannot be parsed as a number. Placeholders: `{input}`, `{cause}`, which explains what is wrong with the input, and `{description}`, which describes the numbers allowed.
+    pub invalid_value: String,
+    /// Shown when the input of `get_parsed` cannot be parsed. Placeholders: `{input}` and `{error}`, the error returned by the type's parser.
+    pub parse_error: String,
+    /// Describes the numbers of an integer type. Placeholders: `{min}` and `{max}`.
+    pub whole_number: String,
+    /// Describes the numbers of a floating point type.
+    pub decimal_number: String,
+    /// Describes the numbers of any other type read by `get_number`, such as `NonZeroU32`.
+    pub any_number: String,
+    /// Explains that the input is not a number. Placeholder: `{input}`.
+    pub not_a_number: String,
+    /// Explains that the input has a fractional part where a whole number is needed. Placeholder: `{input}`.
+    pub not_a_whole_number: String,
+    /// Explains that the input is too large for the type. Placeholder: `{input}`.
+    pub number_too_large: String,
+    /// Explains that the input is too small for the type. Placeholder: `{input}`.
+    pub number_too_small: String,
+    /// Shown when the input is not one of the choices. Placeholder: `{input}`.
+    pub invalid_choice: String,
+    /// Shown when the input is not one of the choices, if the choices should be listed. Placeholders: `{input}` and `{choices}`.
+    pub invalid_choice_with_choices: String,
+    /// Shown after an invalid string choice when choices are case-sensitive.
+    pub case_sensitive: String,
+    /// Shown after an invalid string choice when choices are not case-sensitive.
+    pub case_insensitive: String,
+    /// Lists the choices of a checklist when selections are typed. Placeholder: `{choices}`.
+    pub checklist_choices: String,
+    /// Asks for typed checklist selections.
+    pub checklist_prompt: String,
+    /// The keys available in an interactive checklist.
+    pub checklist_keys: String,
+    /// Shown when fewer items are selected than required. Placeholders: `{count}` and `{min}`.
+    pub too_few_selected: String,
+    /// Shown when more items are selected than allowed. Placeholders: `{count}` and `{max}`.
+    pub too_many_selected: String,
+    /// Asks for the page to jump to in a pager.
+    pub page_prompt: String,
+    /// The page counter below the items of a pager. Placeholders: `{page}` and `{pages}`.
+    pub page_footer: String,
+    /// The page counter of a list filtered by a search. Placeholders: `{page}`, `{pages}`, `{matches}`, `{total}` and `{filter}`.
+    pub filtered_page_footer: String,
+    /// The keys available in `paginated_list`.
+    pub list_keys: String,
+    /// The commands available in `paginated_list` when keypresses can't be read.
+    pub list_help: String,
+    /// The keys available in `paginated_select`.
+    pub select_keys: String,
+    /// The commands available in `paginated_select` when keypresses can't be read.
+    pub select_help: String,
+    /// Asks for the term to filter a list by.
+    pub list_search_prompt: String,
+    /// Asks for a search term typed in place in a pager.
+    pub inline_search: String,
+    /// Asks for a page number typed in place in a pager.
+    pub inline_page: String,
+    /// Asks for a line number typed in place in a pager.
+    pub inline_line: String,
+    /// Asks for a column typed in place in a table pager.
+    pub inline_sort: String,
+    /// Asks for a command typed in place in a pager.
+    pub inline_command: String,
+    /// The keys available in `paginated_source`.
+    pub source_keys: String,
+    /// The commands available in `paginated_source` when keypresses can't be read.
+    pub source_help: String,
+    /// The page counter of a filtered or sorted table. Placeholders: `{page}`, `{pages}` and `{details}`, which lists the filter and sort order.
+    pub table_page_footer: String,
+    /// The part of a table's page counter describing a filter. Placeholders: `{matches}`, `{total}` and `{filter}`.
+    pub table_filter_status: String,
+    /// The part of a table's page counter describing an ascending sort. Placeholder: `{column}`.
+    pub table_sorted_ascending: String,
+    /// The part of a table's page counter describing a descending sort. Placeholder: `{column}`.
+    pub table_sorted_descending: String,
+    /// The keys available in `paginated_table`.
+    pub table_keys: String,
+    /// The commands available in `paginated_table` when keypresses can't be read.
+    pub table_help: String,
+    /// Asks for the term to filter a table by.
+    pub table_search_prompt: String,
+    /// Shown when sorting by a column that doesn't exist. Placeholder: `{input}`.
+    pub unknown_column: String,
+    /// The page counter of `page_text`. Placeholders: `{page}`, `{pages}`, `{first}`, `{last}` and `{total}`.
+    pub text_page_footer: String,
+    /// The keys available in `page_text`.
+    pub text_keys: String,
+    /// The commands available in `page_text` when keypresses can't be read.
+    pub text_help: String,
+    /// Asks for the term to search the text for.
+    pub text_search_prompt: String,
+    /// Shown when jumping past the last line of the text. Placeholder: `{input}`.
+    pub no_such_line: String,
+    /// Shown when no lines of the text contain the search term. Placeholder: `{input}`.
+    pub no_matching_lines: String,
+    /// The state of `follow_text` while it shows new lines as they arrive.
+    pub follow_following: String,
+    /// The state of `follow_text` while scrolled back.
+    pub follow_paused: String,
+    /// The state of `follow_text` while scrolled back and new lines have arrived. Placeholder: `{count}`.
+    pub follow_paused_new_lines: String,
+    /// The line counter of `follow_text`. Placeholders: `{state}`, `{first}`, `{last}` and `{total}`.
+    pub follow_footer: String,
+    /// The line counter of `follow_text` once the text has ended. Placeholders: `{state}`, `{first}`, `{last}` and `{total}`.
+    pub follow_footer_finished: String,
+    /// The keys available in `follow_text`.
+    pub follow_keys: String,
+    /// The line shown by `follow_reader` when reading fails. Placeholder: `{error}`.
+    pub follow_read_error: String,
+    /// The keys available in `browse_tree`.
+    pub tree_keys: String,
+    /// The commands available in `browse_tree` when keypresses can't be read.
+    pub tree_help: String,
+    /// Asks for the term to search for in `fuzzy_select` when keypresses can't be read.
+    pub fuzzy_prompt: String,
+    /// The keys available in `fuzzy_select`. Placeholders: `{matches}` and `{total}`.
+    pub fuzzy_keys: String,
+    /// Lists the number of matches in `fuzzy_select` when keypresses can't be read. Placeholders: `{matches}` and `{total}`.
+    pub fuzzy_matches: String,
+    /// Shown when no items match the search term of `fuzzy_select`. Placeholder: `{input}`.
+    pub fuzzy_no_matches: String,
+}
+
+impl Default for Messages {
+    fn default() -> Messages {
+        Messages {
+            locale: Locale::English,
+            input_too_long: String::from("Your input is {over} {over|character|characters} higher than the {limit} character limit. Please try again."),
+            input_empty: String::from("Your input cannot be empty."),
+            below_min: String::from("Your input ({input}) is lower than the minimum allowed value of {min}."),
+            above_max: String::from("Your input ({input}) is larger than the maximum allowed value of {max}."),
+            invalid_value: String::from("{cause} Please enter {description}."),
+            parse_error: String::from("Your input ({input}) is not valid: {error}"),
+            whole_number: String::from("a whole number between {min} and {max}"),
+            decimal_number: String::from("a decimal number"),
+            any_number: String::from("a number"),
+            not_a_number: String::from("Your input ({input}) is not a number."),
+            not_a_whole_number: String::from("Your input ({input}) is not a whole number."),
+            number_too_large: String::from("Your input ({input}) is too large."),
+            number_too_small: String::from("Your input ({input}) is too small."),
+            invalid_choice: String::from("Your input ({input}) is not a valid choice."),
+            invalid_choice_with_choices: String::from("Your input ({input}) is not an option of the choices: {choices}"),
+            case_sensitive: String::from("(Case Sensitive: true)"),
+            case_insensitive: String::from("(Case Sensitive: false)"),
+            checklist_choices: String::from("Choices: {choices}"),
+            checklist_prompt: String::from("Enter your selections separated by commas."),
+            checklist_keys: String::from("(Space to toggle, A to toggle all, Enter to confirm)"),
+            too_few_selected: String::from("You have selected {count} {count|item|items}, but at least {min} must be selected."),
+            too_many_selected: String::from("You have selected {count} {count|item|items}, but at most {max} can be selected."),
+            page_prompt: String::from("Enter the page you would like to view."),
+            page_footer: String::from("(Page {page} of {pages})"),
+            filtered_page_footer: String::from("(Page {page} of {pages}, {matches} of {total} {total|item|items} match \"{filter}\")"),
+            list_keys: String::from("[N]ext  [P]revious  [Home/End] first/last  [#] page  [/] search  [C]lear  [Q]uit"),
+            list_help: String::from("Press N to view the next page, P for previous, F for first, L for last, S or a page number for a specific page, / to search, C to clear the search, or E to Exit."),
+            select_keys: String::from("[#] select  [Enter] type a name  [N]ext  [P]revious  [S] page  [/] search  [Q]uit"),
+            select_help: String::from("Enter the number or name of an item to select it, or press N to view the next page, P for previous, F for first, L for last, S for a specific page, / to search, C to clear the search, or E to Exit."),
+            list_search_prompt: String::from("Enter a search term to filter the list by, or leave empty to clear the search."),
+            inline_search: String::from("Search: "),
+            inline_page: String::from("Go to page: "),
+            inline_line: String::from("Go to line: "),
+            inline_sort: String::from("Sort by column: "),
+            inline_command: String::from("Command: "),
+            source_keys: String::from("[N]ext  [P]revious  [Home/End] first/last  [#] page  [Q]uit"),
+            source_help: String::from("Press N to view the next page, P for previous, F for first, L for last, S or a page number for a specific page, or E to Exit."),
+            table_page_footer: String::from("(Page {page} of {pages}, {details})"),
+            table_filter_status: String::from("{matches} of {total} {total|row|rows} match \"{filter}\""),
+            table_sorted_ascending: String::from("sorted by {column} ascending"),
+            table_sorted_descending: String::from("sorted by {column} descending"),
+            table_keys: String::from("[N]ext  [P]revious  [#] page  [O] sort  [/] search  [C]lear  [Q]uit"),
+            table_help: String::from("Press N to view the next page, P for previous, F for first, L for last, S or a page number for a specific page, O and a column to sort by it, / to search or /column=value to filter a column, C to clear the search, or E to Exit."),
+            table_search_prompt: String::from("Enter a search term, or column=value to filter a column, or leave empty to clear the search."),
+            unknown_column: String::from("There is no column named \"{input}\"."),
+            text_page_footer: String::from("(Page {page} of {pages}, lines {first}-{last} of {total})"),
+            text_keys: String::from("[N]ext  [P]revious  [Home/End] first/last  [/] search  [:] line  [Q]uit"),
+            text_help: String::from("Press N to view the next page, P for previous, F for first, L for last, S or a page number for a specific page, / followed by a term to search, : followed by a number to go to a line, or E to Exit."),
+            text_search_prompt: String::from("Enter a search term, or leave empty to repeat the last search."),
+            no_such_line: String::from("There is no line {input}."),
+            no_matching_lines: String::from("No lines contain \"{input}\"."),
+            follow_following: String::from("Following"),
+            follow_paused: String::from("Paused"),
+            follow_paused_new_lines: String::from("Paused, {count} new {count|line|lines}"),
+            follow_footer: String::from("({state}, lines {first}-{last} of {total})"),
+            follow_footer_finished: String::from("({state}, lines {first}-{last} of {total}, end of output)"),
+            follow_keys: String::from("[Up/Down] scroll  [F/End] follow  [Q]uit"),
+            follow_read_error: String::from("Error while reading: {error}"),
+            tree_keys: String::from("[#] expand/collapse  [N]ext  [P]revious  [S] page  [Q]uit"),
+            tree_help: String::from("Enter the number or label of a node to expand or collapse it, or press N to view the next page, P for previous, F for first, L for last, S for a specific page, or E to Exit."),
+            fuzzy_prompt: String::from("Enter a search term, or leave empty to cancel."),
+            fuzzy_keys: String::from("({matches} of {total} {total|item|items} match. Up/Down to move, Enter to select, Esc to cancel)"),
+            fuzzy_matches: String::from("({matches} of {total} {total|item|items} match. Enter the number of your choice, or a new search term.)"),
+            fuzzy_no_matches: String::from("No items match \"{input}\". Enter a different search term."),
+        }
+    }
+}
+
+impl Messages {
+    /// Returns the bundled translations of the messages for a locale.
+    pub fn for_locale(locale: Locale) -> Messages {
+        match locale {
+            Locale::English => Messages::default(),
+            Locale::German => locale::german(),
+            Locale::Spanish => locale::spanish(),
+            Locale::French => locale::french(),
+            Locale::Japanese => locale::japanese(),
+        }
+    }
+}
+
+static MESSAGES: LazyLock<Mutex<Messages>> = LazyLock::new(|| Mutex::new(Messages::default()));
+
+thread_local! {
+    static SCOPED_MESSAGES: RefCell<Vec<Messages>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Sets the messages displayed by every prompt and pager.
+pub fn set_messages(messages: Messages) {
+    *MESSAGES.lock().unwrap() = messages;
+}
+
+/// Sets the messages displayed by every prompt and pager to the bundled translations for a locale, instead of English.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// set_locale(Locale::German);
+/// assert_eq!(messages().input_empty, "Ihre Eingabe darf nicht leer sein.");
+///
+/// // Follow the language named by LC_ALL, LC_MESSAGES or LANG.
+/// set_locale(Locale::from_env().unwrap_or_default());
+/// ```
+pub fn set_locale(locale: Locale) {
+    set_messages(Messages::for_locale(locale));
+}
+
+/// Returns the messages currently in use: those passed to the innermost `with_messages` call on this thread, or else those set with `set_messages`, or else the default messages.
+pub fn messages() -> Messages {
+    SCOPED_MESSAGES
+        .with(|scoped| scoped.borrow().last().cloned())
+        .unwrap_or_else(|| MESSAGES.lock().unwrap().clone())
+}
+
+/// Removes the messages pushed by `with_messages` when dropped, even if the closure panicked.
+struct ScopedMessages;
+
+impl Drop for ScopedMessages {
+    fn drop(&mut self) {
+        SCOPED_MESSAGES.with(|scoped| scoped.borrow_mut().pop());
+    }
+}
+
+/// Runs a closure, such as a single prompt, with different messages than the ones set with `set_messages`. The messages apply only to the current thread and are restored when the closure returns.
+///
+/// # Arguments
+///
+/// * `messages` - The messages displayed while the closure runs.
+/// * `f` - The closure to run.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let age = with_messages(
+///     Messages {
+///         above_max: String::from("Nobody is {input} years old."),
+///         ..messages()
+///     },
+///     || get_number::<u8>(Some("How old are you?"), None, Some(0), Some(130)),
+/// );
+/// ```
+pub fn with_messages<R>(messages: Messages, f: impl FnOnce() -> R) -> R {
+    SCOPED_MESSAGES.with(|scoped| scoped.borrow_mut().push(messages));
+    let _scope = ScopedMessages;
+    f()
+}
+
+/// Replaces each `{name}` placeholder in a template with its value, and each `{name|form|...}` placeholder with the plural form `locale` picks for the value. Placeholders without a value are left unchanged.
+fn fill(template: &str, values: &[(&str, &dyn Display)], locale: Locale) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        filled.push_str(&rest[..start]);
+        let placeholder = &rest[start..];
+        let value = placeholder.find('}').and_then(|end| {
+            let mut parts = placeholder[1..end].split('|');
+            let name = parts.next().unwrap_or_default();
+            let forms: Vec<&str> = parts.collect();
+            let value = values
+                .iter()
+                .find(|(value_name, _)| *value_name == name)
+                .map(|(_, value)| value.to_string())?;
+            if forms.is_empty() {
+                return Some((value, end + 1));
+            }
+            let form = value
+                .parse::<f64>()
+                .map_or(forms.len() - 1, |number| locale.plural_form(number));
+            Some((forms[form.min(forms.len() - 1)].to_string(), end + 1))
+        });
+        match value {
+            Some((value, length)) => {
+                filled.push_str(&value);
+                rest = &placeholder[length..];
+            }
+            None => {
+                filled.push('{');
+                rest = &placeholder[1..];
+            }
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
+
+/// Returns the locale of the messages currently in use.
+pub(crate) fn locale() -> Locale {
+    SCOPED_MESSAGES
+        .with(|scoped| scoped.borrow().last().map(|messages| messages.locale))
+        .unwrap_or_else(|| MESSAGES.lock().unwrap().locale)
+}
+
+/// Returns a message from the messages currently in use, with its placeholders replaced by the given values.
+pub(crate) fn message(
+    template: fn(&Messages) -> &String,
+    values: &[(&str, &dyn Display)],
+) -> String {
+    // The template is copied out before it is filled, since formatting a value may show another message.
+    let scoped = SCOPED_MESSAGES.with(|scoped| {
+        scoped
+            .borrow()
+            .last()
+            .map(|messages| (template(messages).clone(), messages.locale))
+    });
+    let (template, locale) = scoped.unwrap_or_else(|| {
+        let messages = MESSAGES.lock().unwrap();
+        (template(&messages).clone(), messages.locale)
+    });
+    fill(&template, values, locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill() {
+        let english = Locale::English;
+        assert_eq!(
+            fill(
+                "{input} is over {max}.",
+                &[("input", &12), ("max", &10)],
+                english
+            ),
+            "12 is over 10."
+        );
+        assert_eq!(
+            fill("{unknown} {", &[("input", &"{max}")], english),
+            "{unknown} {"
+        );
+        assert_eq!(
+            fill("({input})", &[("input", &"{max}"), ("max", &1)], english),
+            "({max})"
+        );
+    }
+
+    #[test]
+    fn test_fill_plural_forms() {
+        let template = "{count} {count|item|items}";
+        assert_eq!(fill(template, &[("count", &1)], Locale::English), "1 item");
+        assert_eq!(fill(template, &[("count", &0)], Locale::English), "0 items");
+        assert_eq!(fill(template, &[("count", &0)], Locale::French), "0 item");
+        assert_eq!(fill(template, &[("count", &2)], Locale::Japanese), "2 item");
+        assert_eq!(
+            fill(template, &[("count", &"?")], Locale::English),
+            "? items"
+        );
+    }
+
+    #[test]
+    fn test_with_messages() {
+        let custom = Messages {
+            input_empty: String::from("Say something."),
+            ..Messages::default()
+        };
+        let inner = with_messages(custom, || message(|m| &m.input_empty, &[]));
+        assert_eq!(inner, "Say something.");
+        let footer = with_messages(Messages::for_locale(Locale::Spanish), || {
+            message(|m| &m.page_footer, &[("page", &2), ("pages", &5)])
+        });
+        assert_eq!(footer, "(Página 2 de 5)");
+    }
+
+    struct EmptyInput;
+
+    impl Display for EmptyInput {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&message(|m| &m.input_empty, &[]))
+        }
+    }
+
+    #[test]
+    fn test_message_with_nested_message() {
+        let values: [(&str, &dyn Display); 2] =
+            [("cause", &EmptyInput), ("description", &"a number")];
+        assert_eq!(
+            message(|m| &m.invalid_value, &values),
+            "Your input cannot be empty. Please enter a number."
+        );
+        let nested = with_messages(Messages::default(), || {
+            message(|m| &m.invalid_value, &values)
+        });
+        assert_eq!(nested, "Your input cannot be empty. Please enter a number.");
+    }
+}
@@ -0,0 +1,394 @@
+use std::{fmt::Display, num::IntErrorKind, str::FromStr, sync::Mutex};
+
+use crate::{messages, Locale};
+
+/// Why the input could not be parsed as a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberError {
+    /// The input is empty.
+    Empty,
+    /// The input is not a number at all, such as `abc`.
+    NotANumber,
+    /// The input is a number with a fractional part, but only whole numbers are allowed.
+    NotAWholeNumber,
+    /// The input is larger than the largest value of the type.
+    TooLarge,
+    /// The input is smaller than the smallest value of the type.
+    TooSmall,
+}
+
+impl NumberError {
+    /// Returns the message explaining the error to the user.
+    pub(crate) fn message(&self, input: &str) -> String {
+        let values: [(&str, &dyn Display); 1] = [("input", &input)];
+        match self {
+            NumberError::Empty => messages::message(|m| &m.input_empty, &values),
+            NumberError::NotANumber => messages::message(|m| &m.not_a_number, &values),
+            NumberError::NotAWholeNumber => messages::message(|m| &m.not_a_whole_number, &values),
+            NumberError::TooLarge => messages::message(|m| &m.number_too_large, &values),
+            NumberError::TooSmall => messages::message(|m| &m.number_too_small, &values),
+        }
+    }
+}
+
+/// A type of number which describes its values to the user when their input can't be parsed, such as "Please enter a whole number between -128 and 127." instead of the name of the type.
+///
+/// It is implemented for all of Rust's integer and floating point types, which `get_number` and `select_number_from_choices` describe this way. Any other type those functions read, such as `NonZeroU32`, is described as a number.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// assert_eq!(u8::description(), "a whole number between 0 and 255");
+/// let error = "300".parse::<u8>().unwrap_err();
+/// assert_eq!(u8::number_error("300", &error), NumberError::TooLarge);
+/// ```
+pub trait NumberKind: FromStr + PartialOrd + Display + Copy {
+    /// Returns a description of the values of the type, such as "a whole number between 0 and 255" or "a decimal number".
+    fn description() -> String;
+
+    /// Returns why `input` could not be parsed, given the error returned by `from_str`.
+    fn number_error(input: &str, error: &Self::Err) -> NumberError;
+}
+
+macro_rules! whole_number_kind {
+    ($($t:ty),*) => {$(
+        impl NumberKind for $t {
+            fn description() -> String {
+                messages::message(
+                    |m| &m.whole_number,
+                    &[("min", &<$t>::MIN), ("max", &<$t>::MAX)],
+                )
+            }
+
+            fn number_error(input: &str, error: &Self::Err) -> NumberError {
+                match error.kind() {
+                    IntErrorKind::Empty => NumberError::Empty,
+                    IntErrorKind::PosOverflow => NumberError::TooLarge,
+                    IntErrorKind::NegOverflow => NumberError::TooSmall,
+                    _ => match input.trim().parse::<f64>() {
+                        Ok(number) if number.fract() != 0.0 => NumberError::NotAWholeNumber,
+                        Ok(number) if number < <$t>::MIN as f64 => NumberError::TooSmall,
+                        Ok(number) if number > <$t>::MAX as f64 => NumberError::TooLarge,
+                        _ => NumberError::NotANumber,
+                    },
+                }
+            }
+        }
+    )*};
+}
+
+whole_number_kind!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! decimal_number_kind {
+    ($($t:ty),*) => {$(
+        impl NumberKind for $t {
+            fn description() -> String {
+                messages::message(|m| &m.decimal_number, &[])
+            }
+
+            fn number_error(input: &str, _error: &Self::Err) -> NumberError {
+                if input.trim().is_empty() {
+                    NumberError::Empty
+                } else {
+                    NumberError::NotANumber
+                }
+            }
+        }
+    )*};
+}
+
+decimal_number_kind!(f32, f64);
+
+/// The description and error of a type that implements `NumberKind`.
+struct KindFns {
+    description: fn() -> String,
+    number_error: fn(&str) -> NumberError,
+}
+
+macro_rules! number_kind_of {
+    ($($t:ty),*) => {
+        /// Returns how to describe `T` if it is one of Rust's integer or floating point types. `get_number` keeps accepting any type that can be parsed, so the type is recognized by its name rather than by a `NumberKind` bound.
+        fn kind_of<T>() -> Option<KindFns> {
+            let name = std::any::type_name::<T>();
+            $(
+                if name == std::any::type_name::<$t>() {
+                    return Some(KindFns {
+                        description: <$t as NumberKind>::description,
+                        number_error: |input| match input.parse::<$t>() {
+                            Ok(_) => NumberError::NotANumber,
+                            Err(error) => <$t as NumberKind>::number_error(input, &error),
+                        },
+                    });
+                }
+            )*
+            None
+        }
+    };
+}
+
+number_kind_of!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Returns why `input` could not be parsed as a `T`.
+fn number_error<T>(input: &str) -> NumberError {
+    match kind_of::<T>() {
+        Some(kind) => (kind.number_error)(input),
+        None if input.trim().is_empty() => NumberError::Empty,
+        None => NumberError::NotANumber,
+    }
+}
+
+/// Returns a description of the values of `T`.
+fn description<T>() -> String {
+    match kind_of::<T>() {
+        Some(kind) => (kind.description)(),
+        None => messages::message(|m| &m.any_number, &[]),
+    }
+}
+
+/// How `get_number` and `select_number_from_choices` read the numbers the user enters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberParsing {
+    /// Numbers are parsed by the type's `FromStr` implementation, such as `1500` or `-2.5`.
+    #[default]
+    Standard,
+    /// Also accepts `0x`, `0o` and `0b` prefixes, `_` and thousands separators, scientific notation such as `1.5e3`, which is accepted for integers when the value is whole, and the suffixes `%`, `k`, `M` and `G`, such as `2.5k` for 2500. Thousands and decimal separators follow the locale of the messages, such as `1.000,5` in German.
+    Extended,
+}
+
+static NUMBER_PARSING: Mutex<NumberParsing> = Mutex::new(NumberParsing::Standard);
+
+/// Sets how `get_number` and `select_number_from_choices` read numbers. The parsed number is still checked against the minimum and maximum values or the choices.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// set_number_parsing(NumberParsing::Extended);
+/// assert_eq!(number_parsing(), NumberParsing::Extended);
+/// ```
+pub fn set_number_parsing(parsing: NumberParsing) {
+    *NUMBER_PARSING.lock().unwrap() = parsing;
+}
+
+/// Returns how numbers are read, as set with `set_number_parsing`.
+pub fn number_parsing() -> NumberParsing {
+    *NUMBER_PARSING.lock().unwrap()
+}
+
+/// Rewrites an extended number literal, such as `0xff`, `1,500`, `1.5e3` or `2k`, as a plain decimal number that `FromStr` can parse. Shifting the decimal point is done on the digits, so the result is exact.
+fn expand_literal(input: &str, locale: Locale) -> Result<String, NumberError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(NumberError::Empty);
+    }
+    let (negative, unsigned) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let sign = if negative { "-" } else { "" };
+    let radix = match unsigned.get(..2).map(str::to_ascii_lowercase).as_deref() {
+        Some("0x") => Some(16),
+        Some("0o") => Some(8),
+        Some("0b") => Some(2),
+        _ => None,
+    };
+    if let Some(radix) = radix {
+        let digits: String = unsigned[2..].chars().filter(|&c| c != '_').collect();
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(NumberError::NotANumber);
+        }
+        return match u128::from_str_radix(&digits, radix) {
+            Ok(value) => Ok(format!("{}{}", sign, value)),
+            Err(_) if negative => Err(NumberError::TooSmall),
+            Err(_) => Err(NumberError::TooLarge),
+        };
+    }
+    let (number, shift) = match unsigned.chars().last() {
+        Some('%') => (&unsigned[..unsigned.len() - 1], -2),
+        Some('k') | Some('K') => (&unsigned[..unsigned.len() - 1], 3),
+        Some('M') => (&unsigned[..unsigned.len() - 1], 6),
+        Some('G') => (&unsigned[..unsigned.len() - 1], 9),
+        _ => (unsigned, 0),
+    };
+    let (mantissa, exponent) = match number.find(['e', 'E']) {
+        Some(index) => (
+            &number[..index],
+            number[index + 1..]
+                .parse::<i16>()
+                .map_err(|_| NumberError::NotANumber)?,
+        ),
+        None => (number, 0),
+    };
+    let mantissa: String = mantissa.chars().filter(|&c| c != '_').collect();
+    let separators = locale.thousands_separators();
+    let decimal_point = mantissa
+        .rfind(|c| c == locale.decimal_separator() || (c == '.' && !separators.contains(&'.')));
+    let (whole, fraction) = match decimal_point {
+        Some(index) => (&mantissa[..index], &mantissa[index + 1..]),
+        None => (mantissa.as_str(), ""),
+    };
+    let groups: Vec<&str> = whole.split(|c| separators.contains(&c)).collect();
+    if groups.len() > 1
+        && (groups[0].is_empty()
+            || groups[0].len() > 3
+            || groups[1..].iter().any(|group| group.len() != 3))
+    {
+        return Err(NumberError::NotANumber);
+    }
+    let digits = groups.concat() + fraction;
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(NumberError::NotANumber);
+    }
+    let point = (digits.len() - fraction.len()) as i64 + i64::from(exponent) + shift;
+    let (whole, fraction) = if point <= 0 {
+        (
+            String::new(),
+            "0".repeat(point.unsigned_abs() as usize) + &digits,
+        )
+    } else if point as usize >= digits.len() {
+        (
+            digits.clone() + &"0".repeat(point as usize - digits.len()),
+            String::new(),
+        )
+    } else {
+        let point = point as usize;
+        (digits[..point].to_string(), digits[point..].to_string())
+    };
+    let whole = match whole.trim_start_matches('0') {
+        "" => "0",
+        whole => whole,
+    };
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        let sign = if whole == "0" { "" } else { sign };
+        Ok(format!("{}{}", sign, whole))
+    } else {
+        Ok(format!("{}{}.{}", sign, whole, fraction))
+    }
+}
+
+fn parse_standard<T: FromStr>(input: &str) -> Result<T, NumberError> {
+    input.parse::<T>().map_err(|_| number_error::<T>(input))
+}
+
+/// Parses an extended number literal in a locale. Input that is not a literal, such as `inf`, is parsed by `FromStr` instead, unless it contains one of the locale's thousands separators, so that `1.5` is not read as one and a half in German when `1.500` is read as 1500.
+fn parse_extended<T: FromStr>(input: &str, locale: Locale) -> Result<T, NumberError> {
+    match expand_literal(input, locale) {
+        Ok(expanded) => parse_standard(&expanded),
+        Err(error) if input.contains(locale.thousands_separators()) => Err(error),
+        Err(error) => parse_standard(input).map_err(|_| error),
+    }
+}
+
+/// Parses a number as set with `set_number_parsing`, returning the message to show the user if the input is not a valid number of the type.
+pub(crate) fn parse_number<T: FromStr>(input: &str) -> Result<T, String> {
+    let parsed = match number_parsing() {
+        NumberParsing::Standard => parse_standard(input),
+        NumberParsing::Extended => parse_extended(input, messages::locale()),
+    };
+    parsed.map_err(|error| {
+        messages::message(
+            |m| &m.invalid_value,
+            &[
+                ("input", &input),
+                ("cause", &error.message(input)),
+                ("description", &description::<T>()),
+            ],
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use super::*;
+
+    fn error<T: NumberKind>(input: &str) -> NumberError {
+        match input.parse::<T>() {
+            Ok(_) => panic!("{} should not parse", input),
+            Err(error) => T::number_error(input, &error),
+        }
+    }
+
+    #[test]
+    fn test_number_error() {
+        assert_eq!(error::<i8>("128"), NumberError::TooLarge);
+        assert_eq!(error::<i8>("-129"), NumberError::TooSmall);
+        assert_eq!(error::<u8>("-1"), NumberError::TooSmall);
+        assert_eq!(error::<u32>("2.5"), NumberError::NotAWholeNumber);
+        assert_eq!(error::<u8>("1e3"), NumberError::TooLarge);
+        assert_eq!(error::<u32>("1e3"), NumberError::NotANumber);
+        assert_eq!(error::<i64>("ten"), NumberError::NotANumber);
+        assert_eq!(error::<i64>(""), NumberError::Empty);
+        assert_eq!(error::<f64>("ten"), NumberError::NotANumber);
+        assert_eq!(number_error::<u8>("300"), NumberError::TooLarge);
+        assert_eq!(number_error::<NonZeroU32>("0"), NumberError::NotANumber);
+        assert_eq!(number_error::<NonZeroU32>(" "), NumberError::Empty);
+        assert_eq!(description::<i8>(), i8::description());
+        assert_eq!(
+            parse_standard::<NonZeroU32>("7"),
+            Ok(NonZeroU32::new(7).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_expand_literal() {
+        let expand = |input| expand_literal(input, Locale::English);
+        assert_eq!(expand("0xff"), Ok(String::from("255")));
+        assert_eq!(expand("-0b1010_1010"), Ok(String::from("-170")));
+        assert_eq!(expand("0o17"), Ok(String::from("15")));
+        assert_eq!(expand("1,234,567"), Ok(String::from("1234567")));
+        assert_eq!(expand("1_000.50"), Ok(String::from("1000.5")));
+        assert_eq!(expand("1.5e3"), Ok(String::from("1500")));
+        assert_eq!(expand("1.2345e2"), Ok(String::from("123.45")));
+        assert_eq!(expand("25e-4"), Ok(String::from("0.0025")));
+        assert_eq!(expand("2.5k"), Ok(String::from("2500")));
+        assert_eq!(expand("-3G"), Ok(String::from("-3000000000")));
+        assert_eq!(expand("50%"), Ok(String::from("0.5")));
+        assert_eq!(expand("12,34"), Err(NumberError::NotANumber));
+        assert_eq!(expand("0x"), Err(NumberError::NotANumber));
+        assert_eq!(
+            expand("0x1_0000_0000_0000_0000_0000_0000_0000_0000"),
+            Err(NumberError::TooLarge)
+        );
+        assert_eq!(expand(""), Err(NumberError::Empty));
+        assert_eq!(
+            expand_literal("1.234,5", Locale::German),
+            Ok(String::from("1234.5"))
+        );
+        assert_eq!(
+            expand_literal("3.5", Locale::German),
+            Err(NumberError::NotANumber)
+        );
+        assert_eq!(
+            expand_literal("1\u{202f}000,25", Locale::French),
+            Ok(String::from("1000.25"))
+        );
+        assert_eq!(
+            expand_literal("3.5", Locale::French),
+            Ok(String::from("3.5"))
+        );
+    }
+
+    #[test]
+    fn test_parse_extended() {
+        assert_eq!(parse_extended::<f64>("1.5", Locale::English), Ok(1.5));
+        assert_eq!(
+            parse_extended::<f64>("inf", Locale::English),
+            Ok(f64::INFINITY)
+        );
+        assert_eq!(parse_extended::<f64>("1,5", Locale::German), Ok(1.5));
+        assert_eq!(parse_extended::<f64>("1.500", Locale::German), Ok(1500.0));
+        assert_eq!(
+            parse_extended::<f64>("1.5", Locale::German),
+            Err(NumberError::NotANumber)
+        );
+        assert_eq!(
+            parse_extended::<f64>("1.50", Locale::German),
+            Err(NumberError::NotANumber)
+        );
+        assert_eq!(parse_extended::<i32>("1.500", Locale::German), Ok(1500));
+    }
+}
@@ -0,0 +1,573 @@
+use std::{
+    fmt::Display,
+    fs::{File, OpenOptions},
+    io::{self, IsTerminal, Write},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, OnceLock,
+    },
+};
+
+use crate::print_header;
+
+/// The name of the environment variable which sets the output format when it hasn't been set with `set_output_format`, such as `SIMPLE_CLI_OUTPUT=json`.
+pub const OUTPUT_FORMAT_VAR: &str = "SIMPLE_CLI_OUTPUT";
+
+/// The format lists are written in by `print_list_as` and `print_records`, so the output of a command can be read by other programs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Human-readable text, one item per line.
+    #[default]
+    Plain,
+    /// A single JSON array.
+    Json,
+    /// One JSON value per line.
+    Ndjson,
+    /// Comma-separated values.
+    Csv,
+    /// Tab-separated values.
+    Tsv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<OutputFormat, String> {
+        match s.trim().to_lowercase().as_str() {
+            "plain" | "text" => Ok(OutputFormat::Plain),
+            "json" => Ok(OutputFormat::Json),
+            "ndjson" | "jsonl" => Ok(OutputFormat::Ndjson),
+            "csv" => Ok(OutputFormat::Csv),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(format!("Unknown output format: {}", s)),
+        }
+    }
+}
+
+static OUTPUT_FORMAT: Mutex<Option<OutputFormat>> = Mutex::new(None);
+
+/// Sets the output format used by `print_list_as` and `print_records` when a call doesn't pass one, overriding the `SIMPLE_CLI_OUTPUT` environment variable.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// set_output_format(OutputFormat::Csv);
+/// assert_eq!(output_format(), OutputFormat::Csv);
+/// ```
+pub fn set_output_format(format: OutputFormat) {
+    *OUTPUT_FORMAT.lock().unwrap() = Some(format);
+}
+
+/// Returns the output format set with `set_output_format`, or else the one named by the `SIMPLE_CLI_OUTPUT` environment variable, or else `OutputFormat::Plain`.
+pub fn output_format() -> OutputFormat {
+    if let Some(format) = *OUTPUT_FORMAT.lock().unwrap() {
+        return format;
+    }
+    std::env::var(OUTPUT_FORMAT_VAR)
+        .ok()
+        .and_then(|format| format.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Where prompts, repeat messages, validation errors and interactive interfaces such as pagers are written, so they can be kept apart from the program's data on stdout. Lists and tables printed with functions such as `print_list` always go to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PromptOutput {
+    /// Writes prompts to stdout, along with the data.
+    #[default]
+    Stdout,
+    /// Writes prompts to stderr.
+    Stderr,
+    /// Writes prompts directly to the terminal, even if both stdout and stderr are redirected. Falls back to stderr if there is no terminal.
+    Tty,
+}
+
+static PROMPT_OUTPUT: Mutex<PromptOutput> = Mutex::new(PromptOutput::Stdout);
+
+/// Sets where prompts, repeat messages, validation errors and interactive interfaces are written.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// set_prompt_output(PromptOutput::Stderr);
+/// let name = get_string(Some("Enter a name:"), None, None, false);
+/// println!("{}", name);
+/// ```
+pub fn set_prompt_output(output: PromptOutput) {
+    *PROMPT_OUTPUT.lock().unwrap() = output;
+}
+
+/// Returns where prompts are written, as set with `set_prompt_output`.
+pub fn prompt_output() -> PromptOutput {
+    *PROMPT_OUTPUT.lock().unwrap()
+}
+
+/// Returns the terminal opened for writing, or None if the process has no terminal.
+fn tty() -> Option<&'static Mutex<File>> {
+    static TTY: OnceLock<Option<Mutex<File>>> = OnceLock::new();
+    let path = if cfg!(windows) { "CONOUT$" } else { "/dev/tty" };
+    TTY.get_or_init(|| {
+        OpenOptions::new()
+            .write(true)
+            .open(path)
+            .ok()
+            .map(Mutex::new)
+    })
+    .as_ref()
+}
+
+/// Writes to the stream set with `set_prompt_output`.
+pub(crate) struct PromptWriter;
+
+impl Write for PromptWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = match prompt_output() {
+            PromptOutput::Stdout => io::stdout().write(buf),
+            PromptOutput::Stderr => io::stderr().write(buf),
+            PromptOutput::Tty => match tty() {
+                Some(tty) => tty.lock().unwrap().write(buf),
+                None => io::stderr().write(buf),
+            },
+        }?;
+        count_prompt_lines(buf[..written].iter().filter(|&&b| b == b'\n').count());
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match prompt_output() {
+            PromptOutput::Stdout => io::stdout().flush(),
+            PromptOutput::Stderr => io::stderr().flush(),
+            PromptOutput::Tty => match tty() {
+                Some(tty) => tty.lock().unwrap().flush(),
+                None => io::stderr().flush(),
+            },
+        }
+    }
+}
+
+/// The number of lines printed since the current prompt or page began, which `clear_last_prompt` erases.
+static PROMPT_LINES: AtomicUsize = AtomicUsize::new(0);
+
+/// Starts counting the lines printed for a new prompt or page.
+pub(crate) fn begin_prompt() {
+    PROMPT_LINES.store(0, Ordering::Relaxed);
+}
+
+/// Adds to the number of lines printed for the current prompt or page, including lines of input echoed by the terminal.
+pub(crate) fn count_prompt_lines(lines: usize) {
+    PROMPT_LINES.fetch_add(lines, Ordering::Relaxed);
+}
+
+/// Subtracts lines that have been erased from the number of lines printed for the current prompt or page.
+pub(crate) fn uncount_prompt_lines(lines: usize) {
+    let _ = PROMPT_LINES.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+        Some(count.saturating_sub(lines))
+    });
+}
+
+/// Returns the number of lines printed since the current prompt or page began, and starts counting again.
+pub(crate) fn take_prompt_lines() -> usize {
+    PROMPT_LINES.swap(0, Ordering::Relaxed)
+}
+
+/// Returns true if prompts are written to a terminal.
+pub(crate) fn prompt_is_terminal() -> bool {
+    match prompt_output() {
+        PromptOutput::Stdout => io::stdout().is_terminal(),
+        PromptOutput::Stderr => io::stderr().is_terminal(),
+        PromptOutput::Tty => tty().is_some() || io::stderr().is_terminal(),
+    }
+}
+
+/// Quotes a string as a JSON string.
+fn json_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Quotes a CSV field if it contains a comma, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Escapes the backslashes, tabs and line breaks in a TSV field.
+fn tsv_field(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+/// Joins the fields of a CSV or TSV record.
+fn join_fields(fields: &[String], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Tsv => fields
+            .iter()
+            .map(|field| tsv_field(field))
+            .collect::<Vec<String>>()
+            .join("\t"),
+        _ => fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<String>>()
+            .join(","),
+    }
+}
+
+/// Formats the lines written for a list of items in the given format. Each item is written as a string.
+fn list_output(texts: &[String], format: OutputFormat) -> Vec<String> {
+    match format {
+        OutputFormat::Plain => texts.to_vec(),
+        OutputFormat::Json => vec![format!(
+            "[{}]",
+            texts
+                .iter()
+                .map(|text| json_string(text))
+                .collect::<Vec<String>>()
+                .join(",")
+        )],
+        OutputFormat::Ndjson => texts.iter().map(|text| json_string(text)).collect(),
+        OutputFormat::Csv | OutputFormat::Tsv => texts
+            .iter()
+            .map(|text| join_fields(std::slice::from_ref(text), format))
+            .collect(),
+    }
+}
+
+/// Displays a list of items in an output format. The header message is only displayed in the plain format, so that the other formats can be read by other programs.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the list.
+/// * `items` - An array of items of a type with 'Display' trait, each of which is written as a string.
+/// * `format` - An option that can contain the output format, or None to use the format from `output_format`.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// let items = vec!["Moe", "Larry", "Curly"];
+/// print_list_as(Some("My list:"), &items, Some(OutputFormat::Json));
+/// ```
+pub fn print_list_as<T: Display>(
+    header_message: Option<&str>,
+    items: &[T],
+    format: Option<OutputFormat>,
+) {
+    let format = format.unwrap_or_else(output_format);
+    if format == OutputFormat::Plain {
+        print_header(header_message);
+    }
+    let texts: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+    for line in list_output(&texts, format) {
+        println!("{}", line);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod records {
+    use std::fmt;
+
+    use serde::{
+        de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor},
+        Serialize,
+    };
+    use serde_json::{Number, Value};
+
+    use super::{join_fields, output_format, OutputFormat};
+    use crate::{print_header, Table};
+
+    /// A value read back from a serialized record, which keeps the keys of objects in the order they were serialized. `serde_json::Value` sorts them unless serde_json's `preserve_order` feature is enabled, which would change the maps of every other crate using serde_json in the same build.
+    enum RecordValue {
+        Null,
+        Bool(bool),
+        Number(Number),
+        String(String),
+        Array(Vec<RecordValue>),
+        Object(Vec<(String, RecordValue)>),
+    }
+
+    impl RecordValue {
+        /// Returns the value written as JSON.
+        fn to_json(&self) -> String {
+            match self {
+                RecordValue::Null => String::from("null"),
+                RecordValue::Bool(value) => value.to_string(),
+                RecordValue::Number(number) => number.to_string(),
+                RecordValue::String(text) => Value::String(text.clone()).to_string(),
+                RecordValue::Array(values) => format!(
+                    "[{}]",
+                    values
+                        .iter()
+                        .map(RecordValue::to_json)
+                        .collect::<Vec<String>>()
+                        .join(",")
+                ),
+                RecordValue::Object(entries) => format!(
+                    "{{{}}}",
+                    entries
+                        .iter()
+                        .map(|(key, value)| format!(
+                            "{}:{}",
+                            Value::String(key.clone()),
+                            value.to_json()
+                        ))
+                        .collect::<Vec<String>>()
+                        .join(",")
+                ),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RecordValue {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RecordValue, D::Error> {
+            deserializer.deserialize_any(RecordValueVisitor)
+        }
+    }
+
+    struct RecordValueVisitor;
+
+    impl<'de> Visitor<'de> for RecordValueVisitor {
+        type Value = RecordValue;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a JSON value")
+        }
+
+        fn visit_unit<E>(self) -> Result<RecordValue, E> {
+            Ok(RecordValue::Null)
+        }
+
+        fn visit_bool<E>(self, value: bool) -> Result<RecordValue, E> {
+            Ok(RecordValue::Bool(value))
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<RecordValue, E> {
+            Ok(RecordValue::Number(value.into()))
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<RecordValue, E> {
+            Ok(RecordValue::Number(value.into()))
+        }
+
+        fn visit_f64<E>(self, value: f64) -> Result<RecordValue, E> {
+            Ok(Number::from_f64(value).map_or(RecordValue::Null, RecordValue::Number))
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<RecordValue, E> {
+            Ok(RecordValue::String(value.to_string()))
+        }
+
+        fn visit_string<E>(self, value: String) -> Result<RecordValue, E> {
+            Ok(RecordValue::String(value))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<RecordValue, A::Error> {
+            let mut values = Vec::new();
+            while let Some(value) = seq.next_element()? {
+                values.push(value);
+            }
+            Ok(RecordValue::Array(values))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<RecordValue, A::Error> {
+            let mut entries = Vec::new();
+            while let Some(entry) = map.next_entry()? {
+                entries.push(entry);
+            }
+            Ok(RecordValue::Object(entries))
+        }
+    }
+
+    /// Returns the text of a field in a CSV, TSV or plain record. Nested arrays and objects are written as JSON.
+    fn field_text(value: &RecordValue) -> String {
+        match value {
+            RecordValue::Null => String::new(),
+            RecordValue::String(text) => text.clone(),
+            value => value.to_json(),
+        }
+    }
+
+    /// Returns the fields of a record: the values of an object or array, or the record itself.
+    fn fields(value: &RecordValue) -> Vec<String> {
+        match value {
+            RecordValue::Object(entries) => {
+                entries.iter().map(|(_, value)| field_text(value)).collect()
+            }
+            RecordValue::Array(values) => values.iter().map(field_text).collect(),
+            value => vec![field_text(value)],
+        }
+    }
+
+    /// Formats the lines written for a list of records, each serialized as JSON, in the given format. Records that are objects are written with a header row taken from the keys of the first record in the CSV, TSV and plain formats, in the order the keys were serialized.
+    pub(super) fn records_output(records: &[String], format: OutputFormat) -> Vec<String> {
+        match format {
+            OutputFormat::Json => return vec![format!("[{}]", records.join(","))],
+            OutputFormat::Ndjson => return records.to_vec(),
+            _ => {}
+        }
+        let values: Vec<RecordValue> = records
+            .iter()
+            .map(|record| {
+                serde_json::from_str(record)
+                    .unwrap_or_else(|error| panic!("Unable to read serialized record: {}", error))
+            })
+            .collect();
+        let headers: Option<Vec<String>> = match values.first() {
+            Some(RecordValue::Object(entries)) => {
+                Some(entries.iter().map(|(key, _)| key.clone()).collect())
+            }
+            _ => None,
+        };
+        let field_rows = values.iter().map(|value| match (value, &headers) {
+            (RecordValue::Object(entries), Some(headers)) => headers
+                .iter()
+                .map(|key| {
+                    entries
+                        .iter()
+                        .find(|(name, _)| name == key)
+                        .map_or(String::new(), |(_, value)| field_text(value))
+                })
+                .collect(),
+            (value, _) => fields(value),
+        });
+        match format {
+            OutputFormat::Plain => match &headers {
+                Some(headers) => {
+                    let mut table = Table::new(headers);
+                    for row in field_rows {
+                        table.add_row(row);
+                    }
+                    table.to_string().lines().map(String::from).collect()
+                }
+                None => field_rows.map(|row| row.join("  ")).collect(),
+            },
+            _ => headers
+                .iter()
+                .cloned()
+                .chain(field_rows)
+                .map(|row| join_fields(&row, format))
+                .collect(),
+        }
+    }
+
+    /// Displays a list of records in an output format, such as structs that derive `Serialize`. Records that serialize to objects are displayed as a table in the plain format, and with a header row in the CSV and TSV formats. The header message is only displayed in the plain format. Requires the `serde` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `header_message` - An option that can contain a string slice which holds a header message for the list.
+    /// * `records` - An array of records of a type with 'Serialize' trait.
+    /// * `format` - An option that can contain the output format, or None to use the format from `output_format`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Serialize;
+    /// use simple_cli::*;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Stooge {
+    ///     name: &'static str,
+    ///     age: u32,
+    /// }
+    ///
+    /// let stooges = vec![Stooge { name: "Moe", age: 42 }, Stooge { name: "Larry", age: 39 }];
+    /// print_records(Some("Stooges:"), &stooges, Some(OutputFormat::Csv));
+    /// ```
+    pub fn print_records<T: Serialize>(
+        header_message: Option<&str>,
+        records: &[T],
+        format: Option<OutputFormat>,
+    ) {
+        let format = format.unwrap_or_else(output_format);
+        if format == OutputFormat::Plain {
+            print_header(header_message);
+        }
+        let records: Vec<String> = records
+            .iter()
+            .map(|record| {
+                serde_json::to_string(record)
+                    .unwrap_or_else(|error| panic!("Unable to serialize record: {}", error))
+            })
+            .collect();
+        for line in records_output(&records, format) {
+            println!("{}", line);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use records::print_records;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_output() {
+        let texts = vec![String::from("a \"b\""), String::from("c,d\te")];
+        assert_eq!(
+            list_output(&texts, OutputFormat::Json),
+            vec![r#"["a \"b\"","c,d\te"]"#]
+        );
+        assert_eq!(
+            list_output(&texts, OutputFormat::Ndjson),
+            vec![r#""a \"b\"""#, r#""c,d\te""#]
+        );
+        assert_eq!(
+            list_output(&texts, OutputFormat::Csv),
+            vec![r#""a ""b""""#, r#""c,d	e""#]
+        );
+        assert_eq!(
+            list_output(&texts, OutputFormat::Tsv),
+            vec![r#"a "b""#, r#"c,d\te"#]
+        );
+        assert_eq!("NDJSON".parse(), Ok(OutputFormat::Ndjson));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_records_output() {
+        let records = vec![
+            String::from(r#"{"name":"Moe","tags":["a"]}"#),
+            String::from(r#"{"name":"Larry, Jr.","tags":null}"#),
+        ];
+        assert_eq!(
+            records::records_output(&records, OutputFormat::Csv),
+            vec!["name,tags", "Moe,\"[\"\"a\"\"]\"", "\"Larry, Jr.\","]
+        );
+        assert_eq!(
+            records::records_output(&records[..1], OutputFormat::Plain),
+            vec!["name  tags", "----  -----", "Moe   [\"a\"]"]
+        );
+        assert_eq!(
+            records::records_output(&records, OutputFormat::Json),
+            vec![r#"[{"name":"Moe","tags":["a"]},{"name":"Larry, Jr.","tags":null}]"#]
+        );
+        let records = vec![String::from(r#"{"zeta":1,"alpha":{"b":2.5,"a":true}}"#)];
+        assert_eq!(
+            records::records_output(&records, OutputFormat::Tsv),
+            vec!["zeta\talpha", "1\t{\"b\":2.5,\"a\":true}"]
+        );
+    }
+}
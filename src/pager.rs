@@ -0,0 +1,613 @@
+use std::{
+    fmt::Display,
+    io::{self, IsTerminal},
+};
+
+use crate::{
+    count_lines, get_number, get_string, messages, output, print_header, print_prompt, read_line,
+    term, theme, PageSize, PagerScreen, Paginator,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PagerCommand {
+    Next,
+    Previous,
+    First,
+    Last,
+    SpecificPage,
+    GoToPage(usize),
+    GoToLine(usize),
+    Jump(isize),
+    Exit,
+    Select(usize),
+    Filter(Option<String>),
+    ClearFilter,
+    Sort(String),
+}
+
+/// The commands a pager accepts besides moving between pages, selecting items and exiting. Input for the other commands is matched against the items instead, so an item such as "Orange" can still be selected by name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct PagerCommands {
+    /// Searching with `/` and clearing the search with `C`.
+    pub(crate) search: bool,
+    /// Sorting by a column with `O`.
+    pub(crate) sort: bool,
+    /// Jumping to a line with `:`.
+    pub(crate) lines: bool,
+}
+
+pub(crate) fn parse_pager_command(
+    input: &str,
+    number_of_pages: usize,
+    page_items: Option<&[String]>,
+    commands: PagerCommands,
+) -> Option<PagerCommand> {
+    if let Some(term) = input.strip_prefix('/').filter(|_| commands.search) {
+        let term = term.trim();
+        if term.is_empty() {
+            return Some(PagerCommand::Filter(None));
+        }
+        return Some(PagerCommand::Filter(Some(term.to_string())));
+    }
+    match input.to_lowercase().as_str() {
+        "n" => return Some(PagerCommand::Next),
+        "p" => return Some(PagerCommand::Previous),
+        "f" => return Some(PagerCommand::First),
+        "l" => return Some(PagerCommand::Last),
+        "s" => return Some(PagerCommand::SpecificPage),
+        "e" => return Some(PagerCommand::Exit),
+        "c" if commands.search => return Some(PagerCommand::ClearFilter),
+        _ => {}
+    }
+    if let Some(column) = input.strip_prefix(['o', 'O']).filter(|_| commands.sort) {
+        let column = column.trim();
+        if !column.is_empty() {
+            return Some(PagerCommand::Sort(column.to_string()));
+        }
+    }
+    if let Some(line) = input.strip_prefix(':').filter(|_| commands.lines) {
+        return line
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .map(PagerCommand::GoToLine);
+    }
+    if input.starts_with('+') || input.starts_with('-') {
+        return input.parse::<isize>().ok().map(PagerCommand::Jump);
+    }
+    let page_number = match input.get(..1) {
+        Some("s") | Some("S") => input[1..].trim(),
+        _ if page_items.is_none() => input,
+        _ => "",
+    };
+    if let Ok(page) = page_number.parse::<usize>() {
+        if page >= 1 && page <= number_of_pages {
+            return Some(PagerCommand::GoToPage(page));
+        }
+        return None;
+    }
+    let page_items = page_items?;
+    if let Ok(number) = input.parse::<usize>() {
+        if number >= 1 && number <= page_items.len() {
+            return Some(PagerCommand::Select(number - 1));
+        }
+    }
+    page_items
+        .iter()
+        .position(|item| item.to_lowercase() == input.to_lowercase())
+        .map(PagerCommand::Select)
+}
+
+fn read_pager_command(
+    prompt: &str,
+    number_of_pages: usize,
+    page_items: Option<&[String]>,
+    commands: PagerCommands,
+) -> PagerCommand {
+    prompt_println!("{}", theme::hint(prompt));
+    let mut input = String::new();
+    loop {
+        match read_line(&mut input) {
+            Ok(0) => return PagerCommand::Exit,
+            Ok(_n) => {
+                let trimmed = input.trim();
+                match parse_pager_command(trimmed, number_of_pages, page_items, commands) {
+                    Some(command) => return command,
+                    None => prompt_println!(
+                        "{}",
+                        theme::error(messages::message(
+                            |m| &m.invalid_choice,
+                            &[("input", &trimmed)]
+                        ))
+                    ),
+                }
+            }
+            Err(error) => panic!("Unexpected stdin error while reading input: {}", error),
+        }
+        input.clear();
+        prompt_println!("{}", theme::hint(prompt));
+    }
+}
+
+/// Maps a single keypress to a pager command. Returns None for keys that need more input or have no meaning in the pager.
+fn pager_key_command(key: term::Key, commands: PagerCommands) -> Option<PagerCommand> {
+    match key {
+        term::Key::Right | term::Key::Down | term::Key::PageDown => Some(PagerCommand::Next),
+        term::Key::Left | term::Key::Up | term::Key::PageUp => Some(PagerCommand::Previous),
+        term::Key::Home => Some(PagerCommand::First),
+        term::Key::End | term::Key::Char('G') => Some(PagerCommand::Last),
+        term::Key::Escape => Some(PagerCommand::Exit),
+        term::Key::Char(c) => match c.to_ascii_lowercase() {
+            'n' | ' ' => Some(PagerCommand::Next),
+            'p' | 'b' => Some(PagerCommand::Previous),
+            'f' | 'g' => Some(PagerCommand::First),
+            'l' => Some(PagerCommand::Last),
+            'q' | 'e' => Some(PagerCommand::Exit),
+            'c' if commands.search => Some(PagerCommand::ClearFilter),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// How long the pager waits for a keypress before checking whether the terminal was resized, in milliseconds.
+const RESIZE_POLL_MS: i32 = 200;
+
+/// Reads a pager command from a single keypress, returning None without a command if the terminal is resized so the page can be redrawn. Commands that need more input, such as a search term or page number, are typed in place on a new line, which is added to `lines`.
+fn read_pager_key(
+    raw_mode: &term::RawMode,
+    number_of_pages: usize,
+    page_items: Option<&[String]>,
+    commands: PagerCommands,
+    lines: &mut usize,
+) -> Option<PagerCommand> {
+    let size = term::size();
+    let key = loop {
+        if let Some(key) = term::poll_key(RESIZE_POLL_MS) {
+            break key;
+        }
+        if term::size() != size {
+            return None;
+        }
+    };
+    if key == term::Key::CtrlC {
+        term::interrupt(raw_mode);
+    }
+    if let Some(command) = pager_key_command(key, commands) {
+        return Some(command);
+    }
+    let (prompt, initial) = match key {
+        term::Key::Char('/') if commands.search => {
+            (messages::message(|m| &m.inline_search, &[]), String::new())
+        }
+        term::Key::Char('s') | term::Key::Char('S') => {
+            (messages::message(|m| &m.inline_page, &[]), String::new())
+        }
+        term::Key::Char(':') if commands.lines => {
+            (messages::message(|m| &m.inline_line, &[]), String::new())
+        }
+        term::Key::Char('o') | term::Key::Char('O') if commands.sort => {
+            (messages::message(|m| &m.inline_sort, &[]), String::new())
+        }
+        term::Key::Char(c) if c.is_ascii_digit() || c == '+' || c == '-' => {
+            (String::new(), c.to_string())
+        }
+        term::Key::Enter => (messages::message(|m| &m.inline_command, &[]), String::new()),
+        _ => return None,
+    };
+    let input = term::read_inline(raw_mode, &prompt, &initial);
+    *lines += count_lines(&format!(
+        "{}{}",
+        prompt,
+        input.as_deref().unwrap_or(&initial)
+    ));
+    let input = input?;
+    let input = input.trim();
+    match key {
+        term::Key::Char('/') => Some(PagerCommand::Filter(Some(input.to_string()))),
+        term::Key::Char('s') | term::Key::Char('S') => parse_pager_command(
+            &format!("s{}", input),
+            number_of_pages,
+            page_items,
+            commands,
+        ),
+        term::Key::Char(':') => parse_pager_command(
+            &format!(":{}", input),
+            number_of_pages,
+            page_items,
+            commands,
+        ),
+        term::Key::Char('o') | term::Key::Char('O') => parse_pager_command(
+            &format!("o{}", input),
+            number_of_pages,
+            page_items,
+            commands,
+        ),
+        _ => parse_pager_command(input, number_of_pages, page_items, commands),
+    }
+}
+
+/// Reads the next pager command, from a single keypress if raw mode is enabled, or from a typed line otherwise. Lines printed while reading the command are added to `lines`.
+fn read_page_command(
+    raw_mode: Option<&term::RawMode>,
+    key_hint: &str,
+    line_prompt: &str,
+    number_of_pages: usize,
+    page_items: Option<&[String]>,
+    commands: PagerCommands,
+    lines: &mut usize,
+) -> Option<PagerCommand> {
+    match raw_mode {
+        Some(raw_mode) => {
+            let hint = theme::hint(key_hint);
+            prompt_println!("{}", hint);
+            *lines += count_lines(&hint);
+            read_pager_key(raw_mode, number_of_pages, page_items, commands, lines)
+        }
+        None => Some(read_pager_command(
+            line_prompt,
+            number_of_pages,
+            page_items,
+            commands,
+        )),
+    }
+}
+
+/// Enables raw mode for reading single keypresses if the terminal is interactive.
+fn enable_raw_mode(interactive: bool) -> Option<term::RawMode> {
+    if interactive {
+        term::RawMode::enable()
+    } else {
+        None
+    }
+}
+
+/// Returns the page counter displayed below the items of a pager.
+pub(crate) fn page_footer(page: usize, number_of_pages: impl Display) -> String {
+    messages::message(
+        |m| &m.page_footer,
+        &[("page", &page), ("pages", &number_of_pages)],
+    )
+}
+
+/// Asks for the page a pager should jump to, up to `max_page` if the number of pages is known.
+fn read_page_number(max_page: Option<usize>) -> usize {
+    let prompt = messages::message(|m| &m.page_prompt, &[]);
+    get_number(Some(&prompt), Some(&prompt), Some(1), max_page)
+}
+
+/// The number of terminal lines used by the pager below the items: the page counter, the command hint or prompt, and the line the cursor rests on.
+const PAGER_FOOTER_LINES: usize = 3;
+
+/// A pager displayed by `run_pager`, which moves between pages and reads the user's commands, leaving what is drawn on each page and the commands other than moving between pages to the pager.
+pub(crate) trait Pager {
+    /// The value returned by `run_pager` when the pager ends with a result, such as a selected item.
+    type Output;
+
+    /// Creates the paginator positioned on the first page, with `page_size` items per page.
+    fn paginator(&mut self, page_size: usize) -> Paginator;
+
+    /// Returns the commands the pager accepts besides moving between pages, selecting items and exiting.
+    fn commands(&self) -> PagerCommands {
+        PagerCommands::default()
+    }
+
+    /// Returns the number of lines drawn on each page besides the items, such as the headers of a table.
+    fn extra_lines(&self) -> usize {
+        0
+    }
+
+    /// Updates the pager before the current page is drawn, such as by fetching its items.
+    fn prepare(&mut self, _paginator: &mut Paginator) {}
+
+    /// Returns the lines drawn for the current page.
+    fn page_lines(&mut self, paginator: &Paginator) -> Vec<String>;
+
+    /// Returns true if the pager asks the user for an answer, such as a selection, so it reads commands even when there is no terminal to page in.
+    fn prompts(&self) -> bool {
+        false
+    }
+
+    /// Returns the lines printed for the current page when every page is printed one after another without prompting.
+    fn plain_lines(&mut self, paginator: &Paginator) -> Vec<String> {
+        self.page_lines(paginator)
+    }
+
+    /// Returns the page counter drawn below the current page.
+    fn footer(&self, paginator: &Paginator) -> String;
+
+    /// Returns an error to show next to the page counter, such as when a search has no matches.
+    fn status(&mut self) -> Option<String> {
+        None
+    }
+
+    /// Returns the labels of the items on the current page which can be selected by name or number, or None if items can't be selected.
+    fn page_labels(&self, _paginator: &Paginator) -> Option<Vec<String>> {
+        None
+    }
+
+    /// Returns the keys available when keypresses can be read.
+    fn key_hint(&self) -> String;
+
+    /// Returns the commands available when they have to be typed.
+    fn line_help(&self) -> String;
+
+    /// Returns the prompt asking for a search term.
+    fn search_prompt(&self) -> String {
+        String::new()
+    }
+
+    /// Carries out a command other than moving between pages or exiting. Returns the pager's result to end it, or None to keep paging.
+    fn handle(&mut self, command: PagerCommand, paginator: &mut Paginator) -> Option<Self::Output>;
+}
+
+/// Prints every page of a pager to stdout one after another without prompting, for when there is no terminal to page in.
+fn print_pages<P: Pager>(header_message: Option<&str>, pager: &mut P, page_size: usize) {
+    print_header(header_message);
+    let mut paginator = pager.paginator(page_size);
+    let mut printed_page = 0;
+    loop {
+        pager.prepare(&mut paginator);
+        if paginator.current_page() <= printed_page {
+            break;
+        }
+        for line in pager.plain_lines(&paginator) {
+            println!("{}", line);
+        }
+        printed_page = paginator.current_page();
+        if !paginator.next() {
+            break;
+        }
+    }
+}
+
+/// Displays a pager until the user exits it, returning None, or the pager ends with a result. When stdin or the prompt output is not a terminal, such as when the output is redirected to a file, pagers that don't ask for an answer print every page instead.
+pub(crate) fn run_pager<P: Pager>(
+    header_message: Option<&str>,
+    pager: &mut P,
+    page_size: PageSize,
+    screen: PagerScreen,
+) -> Option<P::Output> {
+    let reserved_lines =
+        header_message.map_or(0, count_lines) + pager.extra_lines() + PAGER_FOOTER_LINES;
+    let terminal = io::stdin().is_terminal() && output::prompt_is_terminal();
+    if !terminal && !pager.prompts() {
+        print_pages(header_message, pager, page_size.resolve(reserved_lines));
+        return None;
+    }
+    let mut paginator = pager.paginator(page_size.resolve(reserved_lines));
+    let _alternate_screen = screen.enter();
+    let interactive = term::is_interactive();
+    let mut raw_mode = enable_raw_mode(interactive);
+    let mut lines_to_erase = 0;
+    loop {
+        paginator.set_page_size(page_size.resolve(reserved_lines));
+        pager.prepare(&mut paginator);
+        if lines_to_erase > 0 {
+            term::erase_lines(lines_to_erase);
+        }
+        output::begin_prompt();
+        let mut lines = 0;
+        if print_prompt(header_message) {
+            lines += count_lines(header_message.unwrap_or_default());
+        }
+        for line in pager.page_lines(&paginator) {
+            prompt_println!("{}", line);
+            lines += count_lines(&line);
+        }
+        let footer = match pager.status() {
+            Some(status) => format!(
+                "{}  {}",
+                theme::footer(pager.footer(&paginator)),
+                theme::error(status)
+            ),
+            None => theme::footer(pager.footer(&paginator)),
+        };
+        prompt_println!("{}", footer);
+        lines += count_lines(&footer);
+        let max_page = if paginator.total_known() {
+            Some(paginator.page_count())
+        } else {
+            None
+        };
+        let page_labels = pager.page_labels(&paginator);
+        let command = read_page_command(
+            raw_mode.as_ref(),
+            &pager.key_hint(),
+            &pager.line_help(),
+            max_page.unwrap_or(usize::MAX),
+            page_labels.as_deref(),
+            pager.commands(),
+            &mut lines,
+        );
+        lines_to_erase = if raw_mode.is_some() && !screen.clears() {
+            lines
+        } else {
+            0
+        };
+        let result = match command {
+            None => None,
+            Some(PagerCommand::Next) => {
+                paginator.next();
+                None
+            }
+            Some(PagerCommand::Previous) => {
+                paginator.prev();
+                None
+            }
+            Some(PagerCommand::First) => {
+                paginator.first();
+                None
+            }
+            Some(PagerCommand::Last) => {
+                paginator.last();
+                None
+            }
+            Some(PagerCommand::SpecificPage) => {
+                drop(raw_mode.take());
+                paginator.goto(read_page_number(max_page));
+                raw_mode = enable_raw_mode(interactive);
+                lines_to_erase = 0;
+                None
+            }
+            Some(PagerCommand::GoToPage(page)) => {
+                paginator.goto(page);
+                None
+            }
+            Some(PagerCommand::Jump(offset)) => {
+                paginator.jump(offset);
+                None
+            }
+            Some(PagerCommand::Exit) => return None,
+            Some(PagerCommand::Filter(None)) => {
+                lines_to_erase = 0;
+                drop(raw_mode.take());
+                let term = get_string(Some(&pager.search_prompt()), None, None, true);
+                raw_mode = enable_raw_mode(interactive);
+                pager.handle(PagerCommand::Filter(Some(term)), &mut paginator)
+            }
+            Some(command) => pager.handle(command, &mut paginator),
+        };
+        if screen.clears() {
+            term::clear_screen();
+        }
+        if result.is_some() {
+            return result;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pager_command() {
+        let all = PagerCommands {
+            search: true,
+            sort: true,
+            lines: true,
+        };
+        let page_items = vec![String::from("Moe"), String::from("Larry")];
+        assert_eq!(
+            parse_pager_command("n", 5, None, all),
+            Some(PagerCommand::Next)
+        );
+        assert_eq!(
+            parse_pager_command("E", 5, None, all),
+            Some(PagerCommand::Exit)
+        );
+        assert_eq!(
+            parse_pager_command("f", 5, None, all),
+            Some(PagerCommand::First)
+        );
+        assert_eq!(
+            parse_pager_command("L", 5, None, all),
+            Some(PagerCommand::Last)
+        );
+        assert_eq!(
+            parse_pager_command("s", 5, None, all),
+            Some(PagerCommand::SpecificPage)
+        );
+        assert_eq!(
+            parse_pager_command("3", 5, None, all),
+            Some(PagerCommand::GoToPage(3))
+        );
+        assert_eq!(parse_pager_command("6", 5, None, all), None);
+        assert_eq!(parse_pager_command("0", 5, None, all), None);
+        assert_eq!(
+            parse_pager_command("s 4", 5, Some(&page_items), all),
+            Some(PagerCommand::GoToPage(4))
+        );
+        assert_eq!(
+            parse_pager_command("+5", 5, None, all),
+            Some(PagerCommand::Jump(5))
+        );
+        assert_eq!(
+            parse_pager_command("-3", 5, Some(&page_items), all),
+            Some(PagerCommand::Jump(-3))
+        );
+        assert_eq!(parse_pager_command("+x", 5, None, all), None);
+        assert_eq!(
+            parse_pager_command(":120", 5, None, all),
+            Some(PagerCommand::GoToLine(120))
+        );
+        assert_eq!(parse_pager_command(":x", 5, None, all), None);
+        assert_eq!(
+            parse_pager_command("2", 5, Some(&page_items), all),
+            Some(PagerCommand::Select(1))
+        );
+        assert_eq!(
+            parse_pager_command("moe", 5, Some(&page_items), all),
+            Some(PagerCommand::Select(0))
+        );
+        assert_eq!(parse_pager_command("3", 5, Some(&page_items), all), None);
+        assert_eq!(
+            parse_pager_command("Curly", 5, Some(&page_items), all),
+            None
+        );
+        assert_eq!(
+            parse_pager_command("/", 5, None, all),
+            Some(PagerCommand::Filter(None))
+        );
+        assert_eq!(
+            parse_pager_command("/ hip ", 5, None, all),
+            Some(PagerCommand::Filter(Some(String::from("hip"))))
+        );
+        assert_eq!(
+            parse_pager_command("c", 5, None, all),
+            Some(PagerCommand::ClearFilter)
+        );
+        assert_eq!(
+            parse_pager_command("O age", 5, None, all),
+            Some(PagerCommand::Sort(String::from("age")))
+        );
+        assert_eq!(parse_pager_command("o", 5, None, all), None);
+    }
+
+    #[test]
+    fn test_parse_pager_command_scoping() {
+        let page_items = vec![String::from("Apple"), String::from("Orange")];
+        let none = PagerCommands::default();
+        assert_eq!(
+            parse_pager_command("Orange", 5, Some(&page_items), none),
+            Some(PagerCommand::Select(1))
+        );
+        assert_eq!(parse_pager_command("O age", 5, None, none), None);
+        assert_eq!(parse_pager_command("/hip", 5, None, none), None);
+        assert_eq!(parse_pager_command("c", 5, None, none), None);
+        assert_eq!(parse_pager_command(":12", 5, None, none), None);
+        assert_eq!(pager_key_command(term::Key::Char('c'), none), None);
+    }
+
+    #[test]
+    fn test_pager_key_command() {
+        let all = PagerCommands {
+            search: true,
+            sort: true,
+            lines: true,
+        };
+        assert_eq!(
+            pager_key_command(term::Key::Char(' '), all),
+            Some(PagerCommand::Next)
+        );
+        assert_eq!(
+            pager_key_command(term::Key::PageUp, all),
+            Some(PagerCommand::Previous)
+        );
+        assert_eq!(
+            pager_key_command(term::Key::Home, all),
+            Some(PagerCommand::First)
+        );
+        assert_eq!(
+            pager_key_command(term::Key::End, all),
+            Some(PagerCommand::Last)
+        );
+        assert_eq!(
+            pager_key_command(term::Key::Char('Q'), all),
+            Some(PagerCommand::Exit)
+        );
+        assert_eq!(pager_key_command(term::Key::Char('/'), all), None);
+        assert_eq!(pager_key_command(term::Key::Char('7'), all), None);
+    }
+}
@@ -0,0 +1,319 @@
+use std::ops::Range;
+
+/// The number of items displayed per page by the paginated list functions.
+///
+/// Integers convert into a fixed page size, so existing calls such as `paginated_list(None, &items, 10, true)` keep working.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let items: Vec<i32> = (1..=500).collect();
+/// paginated_list(Some("Fit to the terminal:"), &items, PageSize::Auto(20), true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageSize {
+    /// Displays the given number of items per page.
+    Fixed(i32),
+    /// Displays as many items as fit in the terminal's height, assuming one line per item, and recomputes the page size when the terminal is resized while the pager waits for a keypress. When commands are typed as lines instead, such as when raw mode is unavailable, the new size takes effect after the next command. Falls back to the given number of items if the terminal size can't be determined.
+    Auto(i32),
+}
+
+impl PageSize {
+    /// Resolves the number of items per page, leaving `reserved_lines` lines of the terminal for the header, footer and prompt. Panics if the number of items is not greater than zero.
+    pub(crate) fn resolve(&self, reserved_lines: usize) -> usize {
+        let items_per_page = match self {
+            PageSize::Fixed(items_per_page) => *items_per_page,
+            PageSize::Auto(fallback) => match crate::term::size() {
+                Some((_, rows)) => rows.saturating_sub(reserved_lines).max(1) as i32,
+                None => *fallback,
+            },
+        };
+        if items_per_page <= 0 {
+            panic!("Items per page must be greater than zero.");
+        }
+        items_per_page as usize
+    }
+}
+
+impl From<i32> for PageSize {
+    fn from(items_per_page: i32) -> PageSize {
+        PageSize::Fixed(items_per_page)
+    }
+}
+
+/// How the paginated list functions update the terminal when the user moves to another page.
+///
+/// Booleans convert into `PagerScreen::Clear` and `PagerScreen::Redraw`, so existing calls such as `paginated_list(None, &items, 10, true)` keep working.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let items: Vec<i32> = (1..=500).collect();
+/// paginated_list(Some("Numbers:"), &items, PageSize::Auto(20), PagerScreen::AlternateScreen);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PagerScreen {
+    /// Redraws the page in place of the previous one when the terminal allows it, or prints each page below the previous one otherwise.
+    Redraw,
+    /// Clears the visible screen before drawing each page, leaving the terminal's scrollback intact.
+    Clear,
+    /// Draws the pages on the terminal's alternate screen, like `less`, and restores the terminal's previous contents when the pager exits.
+    AlternateScreen,
+}
+
+impl PagerScreen {
+    /// Returns true if the screen is cleared before each page is drawn.
+    pub(crate) fn clears(&self) -> bool {
+        *self != PagerScreen::Redraw
+    }
+
+    /// Switches to the alternate screen if needed, returning a guard which switches back when dropped.
+    pub(crate) fn enter(&self) -> Option<crate::term::AlternateScreen> {
+        match self {
+            PagerScreen::AlternateScreen => crate::term::AlternateScreen::enter(),
+            PagerScreen::Redraw | PagerScreen::Clear => None,
+        }
+    }
+}
+
+impl From<bool> for PagerScreen {
+    fn from(clear_on_update: bool) -> PagerScreen {
+        if clear_on_update {
+            PagerScreen::Clear
+        } else {
+            PagerScreen::Redraw
+        }
+    }
+}
+
+/// Keeps track of the current page of a paginated collection of items, independently of how the items are displayed.
+///
+/// Pages are numbered from 1, and there is always at least one page, even if there are no items. A paginator created with `Paginator::unbounded` does not know the total number of items yet, and lets the user move forwards until `set_item_count` is called.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// let items = vec!["Moe", "Larry", "Curly"];
+/// let mut paginator = Paginator::new(items.len(), 2);
+/// assert_eq!(paginator.page_items(&items), &["Moe", "Larry"]);
+/// paginator.next();
+/// assert_eq!(paginator.current_page(), 2);
+/// assert_eq!(paginator.page_items(&items), &["Curly"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paginator {
+    item_count: usize,
+    page_size: usize,
+    current_page: usize,
+    total_known: bool,
+}
+
+impl Paginator {
+    /// Creates a paginator positioned on the first page. Panics if `page_size` is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_count` - The total number of items being paginated.
+    /// * `page_size` - The number of items that will be displayed per page.
+    pub fn new(item_count: usize, page_size: usize) -> Paginator {
+        if page_size == 0 {
+            panic!("Items per page must be greater than zero.");
+        }
+        Paginator {
+            item_count,
+            page_size,
+            current_page: 1,
+            total_known: true,
+        }
+    }
+
+    /// Creates a paginator positioned on the first page for a collection whose total number of items is not known yet. Panics if `page_size` is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - The number of items that will be displayed per page.
+    pub fn unbounded(page_size: usize) -> Paginator {
+        let mut paginator = Paginator::new(0, page_size);
+        paginator.total_known = false;
+        paginator
+    }
+
+    /// Returns true if the total number of items is known.
+    pub fn total_known(&self) -> bool {
+        self.total_known
+    }
+
+    /// Returns the total number of items being paginated, or the number of items known so far if the total is not known.
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    /// Returns the number of items displayed per page.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Returns the current page number, starting from 1.
+    pub fn current_page(&self) -> usize {
+        self.current_page
+    }
+
+    /// Returns the number of pages, which is at least 1. If the total number of items is not known, this is the number of pages known so far.
+    pub fn page_count(&self) -> usize {
+        let page_count = self.item_count.div_ceil(self.page_size).max(1);
+        if self.total_known {
+            page_count
+        } else {
+            page_count.max(self.current_page)
+        }
+    }
+
+    /// Moves to the next page. Returns false if already on the last page.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> bool {
+        self.goto(self.current_page.saturating_add(1))
+    }
+
+    /// Moves to the previous page. Returns false if already on the first page.
+    pub fn prev(&mut self) -> bool {
+        self.current_page > 1 && self.goto(self.current_page - 1)
+    }
+
+    /// Moves to the first page.
+    pub fn first(&mut self) {
+        self.current_page = 1;
+    }
+
+    /// Moves to the last page, or the last page known so far if the total number of items is not known.
+    pub fn last(&mut self) {
+        self.current_page = self.page_count();
+    }
+
+    /// Moves to a specific page. Returns false and stays on the current page if the page does not exist.
+    pub fn goto(&mut self, page: usize) -> bool {
+        if page < 1 || page > self.max_page() {
+            return false;
+        }
+        self.current_page = page;
+        true
+    }
+
+    /// Moves forwards or backwards by a number of pages, stopping at the first or last page.
+    pub fn jump(&mut self, offset: isize) {
+        let page = self.current_page.saturating_add_signed(offset).max(1);
+        self.current_page = page.min(self.max_page());
+    }
+
+    /// Returns the furthest page that can be moved to, which while the total number of items is not known is the last page whose items can still be numbered.
+    fn max_page(&self) -> usize {
+        if self.total_known {
+            self.page_count()
+        } else {
+            usize::MAX / self.page_size
+        }
+    }
+
+    /// Changes the number of items being paginated, such as after filtering them or reaching the end of a collection whose total was not known, and moves back to the last page if the current page no longer exists.
+    pub fn set_item_count(&mut self, item_count: usize) {
+        self.item_count = item_count;
+        self.total_known = true;
+        self.current_page = self.current_page.min(self.page_count());
+    }
+
+    /// Changes the number of items displayed per page, keeping the first item of the current page visible. Panics if `page_size` is zero.
+    pub fn set_page_size(&mut self, page_size: usize) {
+        if page_size == 0 {
+            panic!("Items per page must be greater than zero.");
+        }
+        let first_item = self.page_range().start;
+        self.page_size = page_size;
+        self.current_page = first_item / page_size + 1;
+    }
+
+    /// Returns the range of item indices on the current page.
+    pub fn page_range(&self) -> Range<usize> {
+        let start = (self.current_page - 1).saturating_mul(self.page_size);
+        if !self.total_known {
+            return start..start.saturating_add(self.page_size);
+        }
+        let end = start.saturating_add(self.page_size).min(self.item_count);
+        start.min(end)..end
+    }
+
+    /// Returns the items on the current page.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items being paginated, which should contain `item_count` items.
+    pub fn page_items<'a, T>(&self, items: &'a [T]) -> &'a [T] {
+        let range = self.page_range();
+        &items[range.start.min(items.len())..range.end.min(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginator_navigation() {
+        let mut paginator = Paginator::new(10, 3);
+        assert_eq!(paginator.page_count(), 4);
+        assert!(!paginator.prev());
+        assert!(paginator.next());
+        assert_eq!(paginator.page_range(), 3..6);
+        paginator.last();
+        assert_eq!(paginator.page_range(), 9..10);
+        assert!(!paginator.next());
+        assert!(!paginator.goto(5));
+        assert!(paginator.goto(2));
+        paginator.jump(-5);
+        assert_eq!(paginator.current_page(), 1);
+        paginator.jump(2);
+        assert_eq!(paginator.current_page(), 3);
+    }
+
+    #[test]
+    fn test_paginator_resizing() {
+        let mut paginator = Paginator::new(0, 5);
+        assert_eq!(paginator.page_count(), 1);
+        assert_eq!(paginator.page_range(), 0..0);
+        paginator.set_item_count(12);
+        paginator.last();
+        paginator.set_item_count(4);
+        assert_eq!(paginator.current_page(), 1);
+        paginator.set_item_count(12);
+        paginator.goto(2);
+        paginator.set_page_size(2);
+        assert_eq!(paginator.current_page(), 3);
+        assert_eq!(paginator.page_range(), 4..6);
+    }
+
+    #[test]
+    fn test_unbounded_paginator() {
+        let mut paginator = Paginator::unbounded(10);
+        assert!(!paginator.total_known());
+        assert!(paginator.goto(7));
+        assert_eq!(paginator.page_count(), 7);
+        assert_eq!(paginator.page_range(), 60..70);
+        paginator.jump(-10);
+        assert_eq!(paginator.current_page(), 1);
+        paginator.jump(4);
+        paginator.set_item_count(25);
+        assert!(paginator.total_known());
+        assert_eq!(paginator.current_page(), 3);
+        assert_eq!(paginator.page_range(), 20..25);
+
+        let mut paginator = Paginator::unbounded(10);
+        assert!(!paginator.goto(usize::MAX));
+        assert!(paginator.goto(usize::MAX / 10));
+        assert!(!paginator.next());
+        paginator.first();
+        paginator.jump(isize::MAX);
+        assert_eq!(paginator.current_page(), usize::MAX / 10);
+        assert_eq!(paginator.page_range().len(), 10);
+    }
+}
@@ -0,0 +1,293 @@
+use std::fmt::Display;
+
+use crate::{
+    messages, page_footer,
+    pager::{self, Pager},
+    PageSize, PagerCommand, PagerScreen, Paginator,
+};
+
+/// A source of items which are fetched one page at a time, for lists that are too large to load into memory at once.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+///
+/// struct Squares;
+///
+/// impl PageSource for Squares {
+///     type Item = u64;
+///
+///     fn fetch(&mut self, page: usize, size: usize) -> Vec<u64> {
+///         let start = (page as u64 - 1) * size as u64;
+///         (start..start + size as u64).map(|n| n * n).collect()
+///     }
+/// }
+///
+/// paginated_source(Some("Square numbers:"), &mut Squares, 10, true);
+/// ```
+pub trait PageSource {
+    /// The type of item displayed by the pager.
+    type Item: Display;
+
+    /// Fetches the items on a page, where pages are numbered from 1. Returning fewer than `size` items marks the end of the list.
+    fn fetch(&mut self, page: usize, size: usize) -> Vec<Self::Item>;
+
+    /// Returns the total number of items, if known. The pager shows "Page 3 of ?" while the total is unknown.
+    fn total(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// A page source that pages through an iterator, only pulling and keeping the items that have been viewed.
+pub struct IterSource<I: Iterator> {
+    iter: I,
+    buffer: Vec<String>,
+    exhausted: bool,
+}
+
+impl<I: Iterator> IterSource<I>
+where
+    I::Item: Display,
+{
+    /// Creates a page source from an iterator.
+    pub fn new(iter: I) -> IterSource<I> {
+        IterSource {
+            iter,
+            buffer: Vec::new(),
+            exhausted: false,
+        }
+    }
+}
+
+impl<I: Iterator> PageSource for IterSource<I>
+where
+    I::Item: Display,
+{
+    type Item = String;
+
+    fn fetch(&mut self, page: usize, size: usize) -> Vec<String> {
+        let start = (page - 1).saturating_mul(size);
+        let end = start.saturating_add(size);
+        while !self.exhausted && self.buffer.len() < end {
+            match self.iter.next() {
+                Some(item) => self.buffer.push(item.to_string()),
+                None => self.exhausted = true,
+            }
+        }
+        let end = end.min(self.buffer.len());
+        self.buffer[start.min(end)..end].to_vec()
+    }
+
+    fn total(&self) -> Option<usize> {
+        if self.exhausted {
+            Some(self.buffer.len())
+        } else {
+            None
+        }
+    }
+}
+
+/// Displays a paginated list of items fetched one page at a time from a page source. Navigation works the same as in `paginated_list`, except that searching is not available. Until the total number of items is known, jumping ahead goes no further than the page after the furthest page viewed, so that an endless source is never read to the end, and jumping to the last page goes to the furthest page viewed.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the paginated list.
+/// * `source` - The page source which the items are fetched from.
+/// * `items_per_page` - The number of items that will be displayed per page, or `PageSize::Auto` to fit the page to the terminal's height.
+/// * `clear_on_update` - A boolean which denotes whether the terminal should clear each time the user navigates to a new page. Pass `PagerScreen::AlternateScreen` instead to show the pages on the alternate screen and restore the terminal's previous contents on exit.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let mut source = IterSource::new((1..).map(|n| format!("Row {}", n)));
+/// paginated_source(Some("Query results:"), &mut source, 20, true);
+/// ```
+pub fn paginated_source<S: PageSource>(
+    header_message: Option<&str>,
+    source: &mut S,
+    items_per_page: impl Into<PageSize>,
+    clear_on_update: impl Into<PagerScreen>,
+) {
+    let mut pager = SourcePager {
+        source,
+        fetched_page: None,
+        page_items: Vec::new(),
+        items_seen: 0,
+    };
+    pager::run_pager(
+        header_message,
+        &mut pager,
+        items_per_page.into(),
+        clear_on_update.into(),
+    );
+}
+
+/// The pager behind `paginated_source`, which keeps the items of the page it fetched last and counts the items on the pages viewed so far.
+struct SourcePager<'a, S: PageSource> {
+    source: &'a mut S,
+    fetched_page: Option<(usize, usize)>,
+    page_items: Vec<S::Item>,
+    items_seen: usize,
+}
+
+impl<S: PageSource> Pager for SourcePager<'_, S> {
+    type Output = ();
+
+    fn paginator(&mut self, page_size: usize) -> Paginator {
+        match self.source.total() {
+            Some(total) => Paginator::new(total, page_size),
+            None => Paginator::unbounded(page_size),
+        }
+    }
+
+    fn prepare(&mut self, paginator: &mut Paginator) {
+        let page_size = paginator.page_size();
+        if !paginator.total_known() {
+            let next_page = self.items_seen / page_size + 1;
+            if paginator.current_page() > next_page {
+                paginator.goto(next_page);
+            }
+        }
+        if self.fetched_page == Some((paginator.current_page(), page_size)) {
+            return;
+        }
+        self.page_items = self.source.fetch(paginator.current_page(), page_size);
+        let start = paginator.page_range().start;
+        self.items_seen = self.items_seen.max(start + self.page_items.len());
+        if !paginator.total_known() && self.page_items.len() < page_size {
+            if let Some(total) = self.source.total() {
+                paginator.set_item_count(total);
+            } else if self.page_items.is_empty() {
+                paginator.set_item_count(self.items_seen);
+            } else {
+                paginator.set_item_count(start + self.page_items.len());
+            }
+            if self.page_items.is_empty() && paginator.current_page() > 1 {
+                self.page_items = self.source.fetch(paginator.current_page(), page_size);
+            }
+        }
+        self.fetched_page = Some((paginator.current_page(), page_size));
+    }
+
+    fn page_lines(&mut self, _paginator: &Paginator) -> Vec<String> {
+        self.page_items
+            .iter()
+            .map(|item| item.to_string())
+            .collect()
+    }
+
+    fn footer(&self, paginator: &Paginator) -> String {
+        if paginator.total_known() {
+            page_footer(paginator.current_page(), paginator.page_count())
+        } else {
+            page_footer(paginator.current_page(), "?")
+        }
+    }
+
+    fn key_hint(&self) -> String {
+        messages::message(|m| &m.source_keys, &[])
+    }
+
+    fn line_help(&self) -> String {
+        messages::message(|m| &m.source_help, &[])
+    }
+
+    fn handle(&mut self, _command: PagerCommand, _paginator: &mut Paginator) -> Option<()> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_source() {
+        let mut source = IterSource::new(1..=7);
+        assert_eq!(source.fetch(2, 3), vec!["4", "5", "6"]);
+        assert_eq!(source.buffer.len(), 6);
+        assert_eq!(source.total(), None);
+        assert_eq!(source.fetch(1, 3), vec!["1", "2", "3"]);
+        assert_eq!(source.fetch(3, 3), vec!["7"]);
+        assert_eq!(source.total(), Some(7));
+        assert_eq!(source.fetch(5, 3), Vec::<String>::new());
+    }
+
+    struct Numbers(usize);
+
+    impl PageSource for Numbers {
+        type Item = usize;
+
+        fn fetch(&mut self, page: usize, size: usize) -> Vec<usize> {
+            let start = (page - 1) * size;
+            (start..(start + size).min(self.0)).collect()
+        }
+    }
+
+    fn source_pager(source: &mut Numbers) -> SourcePager<'_, Numbers> {
+        SourcePager {
+            source,
+            fetched_page: None,
+            page_items: Vec::new(),
+            items_seen: 0,
+        }
+    }
+
+    #[test]
+    fn test_source_pager_unknown_total() {
+        let mut source = Numbers(25);
+        let mut pager = source_pager(&mut source);
+        let mut paginator = pager.paginator(10);
+        pager.prepare(&mut paginator);
+        assert!(paginator.goto(10));
+        pager.prepare(&mut paginator);
+        assert!(!paginator.total_known());
+        assert_eq!(paginator.current_page(), 2);
+        assert!(paginator.goto(3));
+        pager.prepare(&mut paginator);
+        assert!(paginator.total_known());
+        assert_eq!(paginator.page_count(), 3);
+        assert_eq!(pager.page_items, vec![20, 21, 22, 23, 24]);
+
+        let mut source = Numbers(20);
+        let mut pager = source_pager(&mut source);
+        let mut paginator = pager.paginator(10);
+        pager.prepare(&mut paginator);
+        paginator.jump(1);
+        pager.prepare(&mut paginator);
+        paginator.jump(5);
+        pager.prepare(&mut paginator);
+        assert_eq!(paginator.current_page(), 2);
+        assert_eq!(paginator.page_count(), 2);
+        assert_eq!(pager.page_items.len(), 10);
+
+        let mut source = Numbers(0);
+        let mut pager = source_pager(&mut source);
+        let mut paginator = pager.paginator(10);
+        assert!(paginator.goto(4));
+        pager.prepare(&mut paginator);
+        assert_eq!(paginator.current_page(), 1);
+        assert!(paginator.total_known());
+        assert!(pager.page_items.is_empty());
+    }
+
+    #[test]
+    fn test_source_pager_endless_source() {
+        let mut source = IterSource::new(1..);
+        let mut pager = SourcePager {
+            source: &mut source,
+            fetched_page: None,
+            page_items: Vec::new(),
+            items_seen: 0,
+        };
+        let mut paginator = pager.paginator(10);
+        pager.prepare(&mut paginator);
+        paginator.jump(999_999_999);
+        pager.prepare(&mut paginator);
+        assert_eq!(paginator.current_page(), 2);
+        assert_eq!(pager.page_items.first().map(String::as_str), Some("11"));
+        assert_eq!(source.buffer.len(), 20);
+    }
+}
@@ -0,0 +1,661 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+use crate::{
+    matches_filter, messages, page_footer,
+    pager::{self, Pager, PagerCommands},
+    print_header, PageSize, PagerCommand, PagerScreen, Paginator,
+};
+
+/// The horizontal alignment of the text in a table column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+/// The characters used to draw the borders of a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Borders {
+    /// No borders. Columns are separated by spaces and the headers are underlined with dashes.
+    #[default]
+    None,
+    /// Borders drawn with `+`, `-` and `|`.
+    Ascii,
+    /// Borders drawn with box-drawing characters.
+    Unicode,
+}
+
+struct BorderChars {
+    horizontal: char,
+    vertical: char,
+    top: [char; 3],
+    middle: [char; 3],
+    bottom: [char; 3],
+}
+
+impl Borders {
+    fn chars(&self) -> Option<BorderChars> {
+        match self {
+            Borders::None => None,
+            Borders::Ascii => Some(BorderChars {
+                horizontal: '-',
+                vertical: '|',
+                top: ['+', '+', '+'],
+                middle: ['+', '+', '+'],
+                bottom: ['+', '+', '+'],
+            }),
+            Borders::Unicode => Some(BorderChars {
+                horizontal: '─',
+                vertical: '│',
+                top: ['┌', '┬', '┐'],
+                middle: ['├', '┼', '┤'],
+                bottom: ['└', '┴', '┘'],
+            }),
+        }
+    }
+}
+
+type CompareCells = dyn Fn(&str, &str) -> Ordering + Send + Sync;
+
+/// Compares two cells of a column by the key extracted from them.
+#[derive(Clone)]
+struct SortKey(Arc<CompareCells>);
+
+impl fmt::Debug for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SortKey")
+    }
+}
+
+/// A table of rows with column headers, which can be printed with `print_table` or paged through with `paginated_table`. Printing the table with `{}` renders all of its lines.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// let mut table = Table::new(vec!["Name", "Age"]);
+/// table.add_row(vec!["Moe", "42"]);
+/// table.add_row(vec!["Larry", "39"]);
+/// table.align(1, Alignment::Right).borders(Borders::Ascii);
+/// print_table(Some("The stooges:"), &table);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    alignments: Vec<Alignment>,
+    max_widths: Vec<Option<usize>>,
+    sort_keys: Vec<Option<SortKey>>,
+    borders: Borders,
+}
+
+impl Table {
+    /// Creates an empty table with the given column headers.
+    pub fn new<I, D>(headers: I) -> Table
+    where
+        I: IntoIterator<Item = D>,
+        D: Display,
+    {
+        Table {
+            headers: headers
+                .into_iter()
+                .map(|header| header.to_string())
+                .collect(),
+            ..Table::default()
+        }
+    }
+
+    /// Adds a row of cells to the bottom of the table.
+    pub fn add_row<I, D>(&mut self, row: I) -> &mut Table
+    where
+        I: IntoIterator<Item = D>,
+        D: Display,
+    {
+        self.rows
+            .push(row.into_iter().map(|cell| cell.to_string()).collect());
+        self
+    }
+
+    /// Sets the alignment of a column, counting columns from 0. Columns are left-aligned by default.
+    pub fn align(&mut self, column: usize, alignment: Alignment) -> &mut Table {
+        if self.alignments.len() <= column {
+            self.alignments.resize(column + 1, Alignment::default());
+        }
+        self.alignments[column] = alignment;
+        self
+    }
+
+    /// Limits the width of a column to a number of characters, counting columns from 0. Longer cells are truncated with an ellipsis.
+    pub fn max_width(&mut self, column: usize, width: usize) -> &mut Table {
+        if self.max_widths.len() <= column {
+            self.max_widths.resize(column + 1, None);
+        }
+        self.max_widths[column] = Some(width.max(1));
+        self
+    }
+
+    /// Sets the key used to sort a column, counting columns from 0. Columns are sorted by their text by default, so a numeric column needs a key such as `|cell| cell.parse::<i64>().ok()` to sort numerically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use simple_cli::*;
+    /// let mut table = Table::new(vec!["Name", "Age"]);
+    /// table.add_row(vec!["Moe", "42"]).add_row(vec!["Larry", "9"]);
+    /// table.sort_key(1, |cell| cell.parse::<u32>().ok()).sort(1, false);
+    /// assert_eq!(table.row_lines()[0], "Larry  9");
+    /// ```
+    pub fn sort_key<K, F>(&mut self, column: usize, key: F) -> &mut Table
+    where
+        K: Ord,
+        F: Fn(&str) -> K + Send + Sync + 'static,
+    {
+        if self.sort_keys.len() <= column {
+            self.sort_keys.resize(column + 1, None);
+        }
+        self.sort_keys[column] = Some(SortKey(Arc::new(move |a, b| key(a).cmp(&key(b)))));
+        self
+    }
+
+    /// Sorts the rows of the table by a column, counting columns from 0. Rows with equal keys keep their order.
+    pub fn sort(&mut self, column: usize, descending: bool) -> &mut Table {
+        let order = self.sorted_rows(column, descending);
+        self.rows = order
+            .into_iter()
+            .map(|row| self.rows[row].clone())
+            .collect();
+        self
+    }
+
+    /// Sets the borders drawn around the table and between its columns.
+    pub fn borders(&mut self, borders: Borders) -> &mut Table {
+        self.borders = borders;
+        self
+    }
+
+    /// Returns the number of rows in the table, not counting the headers.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true if the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    fn cell(&self, row: usize, column: usize) -> &str {
+        self.rows[row].get(column).map_or("", String::as_str)
+    }
+
+    fn sorted_rows(&self, column: usize, descending: bool) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        let key = self.sort_keys.get(column).cloned().flatten();
+        order.sort_by(|&a, &b| {
+            let (a, b) = if descending { (b, a) } else { (a, b) };
+            let (a, b) = (self.cell(a, column), self.cell(b, column));
+            match &key {
+                Some(SortKey(compare)) => compare(a, b),
+                None => a.cmp(b),
+            }
+        });
+        order
+    }
+
+    /// Finds a column from its header, compared case-insensitively, or from its number counting from 1.
+    fn find_column(&self, name: &str) -> Option<usize> {
+        let name = name.trim();
+        if let Some(column) = self
+            .headers
+            .iter()
+            .position(|header| header.to_lowercase() == name.to_lowercase())
+        {
+            return Some(column);
+        }
+        match name.parse::<usize>() {
+            Ok(number) if number >= 1 && number <= self.column_count() => Some(number - 1),
+            _ => None,
+        }
+    }
+
+    /// Returns the indices of the rows to show, in order. A filter of the form `column=value` keeps the rows whose cell in that column contains the value, and any other filter keeps the rows whose formatted line contains it.
+    fn visible_rows(
+        &self,
+        row_lines: &[String],
+        sort: Option<(usize, bool)>,
+        filter: Option<&str>,
+    ) -> Vec<usize> {
+        let order = match sort {
+            Some((column, descending)) => self.sorted_rows(column, descending),
+            None => (0..self.rows.len()).collect(),
+        };
+        let column_filter = filter
+            .and_then(|filter| filter.split_once('='))
+            .and_then(|(name, value)| Some((self.find_column(name)?, value.trim())));
+        order
+            .into_iter()
+            .filter(|&row| match (column_filter, filter) {
+                (Some((column, value)), _) => matches_filter(self.cell(row, column), value),
+                (None, Some(term)) => matches_filter(&row_lines[row], term),
+                (None, None) => true,
+            })
+            .collect()
+    }
+
+    fn column_count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(Vec::len)
+            .chain(std::iter::once(self.headers.len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        (0..self.column_count())
+            .map(|column| {
+                let widest = std::iter::once(&self.headers)
+                    .chain(self.rows.iter())
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| clean_cell(cell).chars().count())
+                    .max()
+                    .unwrap_or(0);
+                match self.max_widths.get(column).copied().flatten() {
+                    Some(max_width) => widest.min(max_width),
+                    None => widest,
+                }
+            })
+            .collect()
+    }
+
+    fn format_row(&self, row: &[String], widths: &[usize]) -> String {
+        let cells: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(column, &width)| {
+                let cell = row.get(column).map_or("", String::as_str);
+                let alignment = self.alignments.get(column).copied().unwrap_or_default();
+                pad_cell(&truncate_cell(&clean_cell(cell), width), width, alignment)
+            })
+            .collect();
+        match self.borders.chars() {
+            Some(chars) => format!(
+                "{v} {} {v}",
+                cells.join(&format!(" {} ", chars.vertical)),
+                v = chars.vertical
+            ),
+            None => cells.join("  ").trim_end().to_string(),
+        }
+    }
+
+    fn rule(&self, widths: &[usize], corners: fn(&BorderChars) -> [char; 3]) -> Option<String> {
+        let chars = self.borders.chars()?;
+        let [left, middle, right] = corners(&chars);
+        let segments: Vec<String> = widths
+            .iter()
+            .map(|width| chars.horizontal.to_string().repeat(width + 2))
+            .collect();
+        Some(format!(
+            "{}{}{}",
+            left,
+            segments.join(&middle.to_string()),
+            right
+        ))
+    }
+
+    /// Returns the lines drawn above the rows: the top border, the column headers, and the line separating them from the rows.
+    pub fn header_lines(&self) -> Vec<String> {
+        let widths = self.column_widths();
+        let mut lines = Vec::new();
+        lines.extend(self.rule(&widths, |chars| chars.top));
+        lines.push(self.format_row(&self.headers, &widths));
+        match self.rule(&widths, |chars| chars.middle) {
+            Some(rule) => lines.push(rule),
+            None => lines.push(
+                widths
+                    .iter()
+                    .map(|width| "-".repeat(*width))
+                    .collect::<Vec<String>>()
+                    .join("  "),
+            ),
+        }
+        lines
+    }
+
+    /// Returns one formatted line for each row of the table.
+    pub fn row_lines(&self) -> Vec<String> {
+        let widths = self.column_widths();
+        self.rows
+            .iter()
+            .map(|row| self.format_row(row, &widths))
+            .collect()
+    }
+
+    /// Returns the lines drawn below the rows, which is the bottom border if the table has borders.
+    pub fn footer_lines(&self) -> Vec<String> {
+        self.rule(&self.column_widths(), |chars| chars.bottom)
+            .into_iter()
+            .collect()
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self
+            .header_lines()
+            .into_iter()
+            .chain(self.row_lines())
+            .chain(self.footer_lines())
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+fn clean_cell(cell: &str) -> String {
+    cell.replace(['\n', '\r'], " ").replace('\t', "    ")
+}
+
+fn truncate_cell(cell: &str, width: usize) -> String {
+    if cell.chars().count() <= width {
+        return cell.to_string();
+    }
+    let mut truncated: String = cell.chars().take(width.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+fn pad_cell(cell: &str, width: usize, alignment: Alignment) -> String {
+    let padding = width.saturating_sub(cell.chars().count());
+    match alignment {
+        Alignment::Left => format!("{}{}", cell, " ".repeat(padding)),
+        Alignment::Right => format!("{}{}", " ".repeat(padding), cell),
+        Alignment::Center => format!(
+            "{}{}{}",
+            " ".repeat(padding / 2),
+            cell,
+            " ".repeat(padding - padding / 2)
+        ),
+    }
+}
+
+/// Displays a table.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the table.
+/// * `table` - The table to display.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// let mut table = Table::new(vec!["Animal", "Legs"]);
+/// table.add_row(vec!["Hippo", "4"]).add_row(vec!["Bird", "2"]);
+/// print_table(Some("Animals:"), &table);
+/// ```
+pub fn print_table(header_message: Option<&str>, table: &Table) {
+    print_header(header_message);
+    println!("{}", table);
+}
+
+/// Displays a paginated table, repeating the column headers on every page. Navigation and searching work the same as in `paginated_list`, and the rows can also be sorted and filtered by column:
+///
+/// * `O` followed by a column's header or number sorts the rows by that column, and doing it again toggles between ascending and descending order.
+/// * `/column=value`, such as `/age=42`, shows only the rows whose cell in that column contains the value.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the paginated table.
+/// * `table` - The table to display.
+/// * `rows_per_page` - The number of rows that will be displayed per page, or `PageSize::Auto` to fit the page to the terminal's height.
+/// * `clear_on_update` - A boolean which denotes whether the terminal should clear each time the user navigates to a new page. Pass `PagerScreen::AlternateScreen` instead to show the pages on the alternate screen and restore the terminal's previous contents on exit.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let mut table = Table::new(vec!["#", "Square"]);
+/// for n in 1..=100 {
+///     table.add_row(vec![n, n * n]);
+/// }
+/// table.sort_key(0, |cell| cell.parse::<u32>().ok());
+/// table.sort_key(1, |cell| cell.parse::<u32>().ok());
+/// table.align(1, Alignment::Right).borders(Borders::Unicode);
+/// paginated_table(Some("Squares:"), &table, PageSize::Auto(20), true);
+/// ```
+pub fn paginated_table(
+    header_message: Option<&str>,
+    table: &Table,
+    rows_per_page: impl Into<PageSize>,
+    clear_on_update: impl Into<PagerScreen>,
+) {
+    let row_lines = table.row_lines();
+    let mut pager = TablePager {
+        table,
+        header_lines: table.header_lines(),
+        visible: table.visible_rows(&row_lines, None, None),
+        row_lines,
+        footer_lines: table.footer_lines(),
+        sort: None,
+        filter: None,
+        status: None,
+    };
+    pager::run_pager(
+        header_message,
+        &mut pager,
+        rows_per_page.into(),
+        clear_on_update.into(),
+    );
+}
+
+/// The pager behind `paginated_table`, which keeps the order and filter of the rows.
+struct TablePager<'a> {
+    table: &'a Table,
+    header_lines: Vec<String>,
+    row_lines: Vec<String>,
+    footer_lines: Vec<String>,
+    sort: Option<(usize, bool)>,
+    filter: Option<String>,
+    status: Option<String>,
+    visible: Vec<usize>,
+}
+
+impl Pager for TablePager<'_> {
+    type Output = ();
+
+    fn paginator(&mut self, page_size: usize) -> Paginator {
+        Paginator::new(self.visible.len(), page_size)
+    }
+
+    fn commands(&self) -> PagerCommands {
+        PagerCommands {
+            search: true,
+            sort: true,
+            ..PagerCommands::default()
+        }
+    }
+
+    fn extra_lines(&self) -> usize {
+        self.header_lines.len() + self.footer_lines.len()
+    }
+
+    fn page_lines(&mut self, paginator: &Paginator) -> Vec<String> {
+        self.header_lines
+            .iter()
+            .chain(
+                paginator
+                    .page_items(&self.visible)
+                    .iter()
+                    .map(|&row| &self.row_lines[row]),
+            )
+            .chain(self.footer_lines.iter())
+            .cloned()
+            .collect()
+    }
+
+    fn plain_lines(&mut self, paginator: &Paginator) -> Vec<String> {
+        let first_page = paginator.current_page() == 1;
+        let last_page = paginator.current_page() == paginator.page_count();
+        self.header_lines
+            .iter()
+            .filter(|_| first_page)
+            .chain(
+                paginator
+                    .page_items(&self.visible)
+                    .iter()
+                    .map(|&row| &self.row_lines[row]),
+            )
+            .chain(self.footer_lines.iter().filter(|_| last_page))
+            .cloned()
+            .collect()
+    }
+
+    fn footer(&self, paginator: &Paginator) -> String {
+        let mut details = Vec::new();
+        if let Some(term) = &self.filter {
+            details.push(messages::message(
+                |m| &m.table_filter_status,
+                &[
+                    ("matches", &self.visible.len()),
+                    ("total", &self.table.len()),
+                    ("filter", term),
+                ],
+            ));
+        }
+        if let Some((column, descending)) = self.sort {
+            let column = self.table.headers.get(column).map_or("", String::as_str);
+            details.push(if descending {
+                messages::message(|m| &m.table_sorted_descending, &[("column", &column)])
+            } else {
+                messages::message(|m| &m.table_sorted_ascending, &[("column", &column)])
+            });
+        }
+        if details.is_empty() {
+            page_footer(paginator.current_page(), paginator.page_count())
+        } else {
+            messages::message(
+                |m| &m.table_page_footer,
+                &[
+                    ("page", &paginator.current_page()),
+                    ("pages", &paginator.page_count()),
+                    ("details", &details.join(", ")),
+                ],
+            )
+        }
+    }
+
+    fn status(&mut self) -> Option<String> {
+        self.status.take()
+    }
+
+    fn key_hint(&self) -> String {
+        messages::message(|m| &m.table_keys, &[])
+    }
+
+    fn line_help(&self) -> String {
+        messages::message(|m| &m.table_help, &[])
+    }
+
+    fn search_prompt(&self) -> String {
+        messages::message(|m| &m.table_search_prompt, &[])
+    }
+
+    fn handle(&mut self, command: PagerCommand, paginator: &mut Paginator) -> Option<()> {
+        match command {
+            PagerCommand::Sort(name) => match self.table.find_column(&name) {
+                Some(column) => self.sort = Some((column, self.sort == Some((column, false)))),
+                None => {
+                    self.status = Some(messages::message(
+                        |m| &m.unknown_column,
+                        &[("input", &name)],
+                    ));
+                    return None;
+                }
+            },
+            PagerCommand::Filter(term) => {
+                self.filter = term.filter(|term| !term.is_empty());
+            }
+            PagerCommand::ClearFilter => self.filter = None,
+            _ => return None,
+        }
+        self.visible = self
+            .table
+            .visible_rows(&self.row_lines, self.sort, self.filter.as_deref());
+        paginator.set_item_count(self.visible.len());
+        paginator.first();
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_rendering() {
+        let mut table = Table::new(vec!["Name", "Age"]);
+        table.add_row(vec!["Moe", "42"]).add_row(vec!["Larry", "9"]);
+        table.align(1, Alignment::Right);
+        assert_eq!(
+            table.to_string(),
+            "Name   Age\n-----  ---\nMoe     42\nLarry    9"
+        );
+        table.borders(Borders::Ascii);
+        assert_eq!(
+            table.to_string(),
+            "+-------+-----+\n| Name  | Age |\n+-------+-----+\n| Moe   |  42 |\n| Larry |   9 |\n+-------+-----+"
+        );
+    }
+
+    #[test]
+    fn test_table_truncation() {
+        let mut table = Table::new(vec!["Description"]);
+        table.add_row(vec!["A very long\ndescription"]);
+        table.max_width(0, 8).align(0, Alignment::Center);
+        assert_eq!(table.row_lines(), vec!["A very …"]);
+        assert_eq!(pad_cell("ab", 6, Alignment::Center), "  ab  ");
+        assert_eq!(truncate_cell("abc", 3), "abc");
+    }
+
+    #[test]
+    fn test_table_sorting_and_filtering() {
+        let mut table = Table::new(vec!["Name", "Age"]);
+        table
+            .add_row(vec!["Moe", "42"])
+            .add_row(vec!["Larry", "9"])
+            .add_row(vec!["Curly", "42"]);
+        let row_lines = table.row_lines();
+        assert_eq!(
+            table.visible_rows(&row_lines, Some((1, false)), None),
+            vec![0, 2, 1]
+        );
+        table.sort_key(1, |cell| cell.parse::<u32>().ok());
+        assert_eq!(
+            table.visible_rows(&row_lines, Some((1, false)), None),
+            vec![1, 0, 2]
+        );
+        assert_eq!(
+            table.visible_rows(&row_lines, Some((1, true)), None),
+            vec![0, 2, 1]
+        );
+        assert_eq!(
+            table.visible_rows(&row_lines, None, Some("AGE=42")),
+            vec![0, 2]
+        );
+        assert_eq!(
+            table.visible_rows(&row_lines, None, Some("1=r")),
+            vec![1, 2]
+        );
+        assert_eq!(table.visible_rows(&row_lines, None, Some("y")), vec![1, 2]);
+        assert_eq!(table.find_column("3"), None);
+        table.sort(0, true);
+        assert_eq!(table.cell(0, 0), "Moe");
+        let sorted = std::thread::spawn(move || table.sort(1, false).cell(0, 0).to_string());
+        assert_eq!(sorted.join().unwrap(), "Larry");
+    }
+}
@@ -0,0 +1,323 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::output::{self, PromptWriter};
+
+/// A single keypress read while the terminal is in raw mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Key {
+    Char(char),
+    Enter,
+    Backspace,
+    Escape,
+    Tab,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    CtrlC,
+    Unknown,
+}
+
+/// Returns true if prompts are written to a terminal that understands escape sequences. Escape sequences are left out of output that is redirected to a file or another program, or sent to a terminal whose TERM is `dumb`.
+pub(crate) fn escapes_enabled() -> bool {
+    output::prompt_is_terminal() && std::env::var("TERM").map_or(true, |term| term != "dumb")
+}
+
+/// Returns true if stdin is attached to a terminal and prompts are written to one that can display escape sequences, which is required for keypress-driven interfaces.
+pub(crate) fn is_interactive() -> bool {
+    io::stdin().is_terminal() && escapes_enabled()
+}
+
+/// Returns the size of the terminal as (columns, rows), or None if it can't be determined. Falls back to the COLUMNS and LINES environment variables when none of stdout, stderr and stdin is a terminal.
+pub(crate) fn size() -> Option<(usize, usize)> {
+    #[cfg(unix)]
+    for fd in [libc::STDOUT_FILENO, libc::STDERR_FILENO, libc::STDIN_FILENO] {
+        unsafe {
+            let mut winsize: libc::winsize = std::mem::zeroed();
+            if libc::ioctl(fd, libc::TIOCGWINSZ, &mut winsize) == 0
+                && winsize.ws_col > 0
+                && winsize.ws_row > 0
+            {
+                return Some((winsize.ws_col as usize, winsize.ws_row as usize));
+            }
+        }
+    }
+    let columns = std::env::var("COLUMNS").ok()?.parse().ok()?;
+    let rows = std::env::var("LINES").ok()?.parse().ok()?;
+    Some((columns, rows))
+}
+
+/// Returns the width of the terminal in columns, or 80 if it can't be determined.
+pub(crate) fn width() -> usize {
+    size().map_or(80, |(columns, _)| columns)
+}
+
+/// Puts the terminal into raw mode for as long as the guard is alive and restores the previous settings when dropped.
+pub(crate) struct RawMode {
+    #[cfg(unix)]
+    original: libc::termios,
+}
+
+impl RawMode {
+    /// Enables raw mode, returning None if the terminal does not support it.
+    #[cfg(unix)]
+    pub(crate) fn enable() -> Option<RawMode> {
+        if !is_interactive() {
+            return None;
+        }
+        unsafe {
+            let mut original: libc::termios = std::mem::zeroed();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut original) != 0 {
+                return None;
+            }
+            let mut raw = original;
+            raw.c_lflag &= !(libc::ICANON | libc::ECHO | libc::ISIG | libc::IEXTEN);
+            raw.c_iflag &= !(libc::IXON | libc::ICRNL);
+            raw.c_cc[libc::VMIN] = 1;
+            raw.c_cc[libc::VTIME] = 0;
+            if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSAFLUSH, &raw) != 0 {
+                return None;
+            }
+            Some(RawMode { original })
+        }
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn enable() -> Option<RawMode> {
+        None
+    }
+}
+
+impl RawMode {
+    fn restore(&self) {
+        #[cfg(unix)]
+        unsafe {
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.original);
+        }
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
+
+#[cfg(unix)]
+fn read_byte(timeout_ms: Option<i32>) -> Option<u8> {
+    unsafe {
+        if let Some(timeout) = timeout_ms {
+            let mut fds = libc::pollfd {
+                fd: libc::STDIN_FILENO,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            if libc::poll(&mut fds, 1, timeout) <= 0 {
+                return None;
+            }
+        }
+        let mut byte: u8 = 0;
+        match libc::read(
+            libc::STDIN_FILENO,
+            &mut byte as *mut u8 as *mut libc::c_void,
+            1,
+        ) {
+            1 => Some(byte),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn read_byte(_timeout_ms: Option<i32>) -> Option<u8> {
+    None
+}
+
+/// Blocks until a key is pressed and returns it. Must only be called while a `RawMode` guard is alive.
+pub(crate) fn read_key() -> Key {
+    match read_byte(None) {
+        Some(byte) => parse_key(byte),
+        None => panic!("Unexpected stdin error while reading a keypress."),
+    }
+}
+
+/// Waits up to `timeout_ms` milliseconds for a key to be pressed, returning None if no key was pressed in time. Must only be called while a `RawMode` guard is alive.
+pub(crate) fn poll_key(timeout_ms: i32) -> Option<Key> {
+    read_byte(Some(timeout_ms)).map(parse_key)
+}
+
+fn parse_key(byte: u8) -> Key {
+    match byte {
+        3 => Key::CtrlC,
+        9 => Key::Tab,
+        b'\r' | b'\n' => Key::Enter,
+        8 | 127 => Key::Backspace,
+        27 => read_escape_sequence(),
+        byte if byte < 0x80 => Key::Char(byte as char),
+        byte => read_utf8_char(byte),
+    }
+}
+
+fn read_escape_sequence() -> Key {
+    let first = match read_byte(Some(25)) {
+        Some(byte) => byte,
+        None => return Key::Escape,
+    };
+    if first != b'[' && first != b'O' {
+        return Key::Escape;
+    }
+    let mut sequence = Vec::new();
+    while let Some(byte) = read_byte(Some(25)) {
+        sequence.push(byte);
+        if byte.is_ascii_alphabetic() || byte == b'~' {
+            break;
+        }
+    }
+    parse_escape_sequence(&sequence)
+}
+
+fn parse_escape_sequence(sequence: &[u8]) -> Key {
+    match sequence {
+        b"A" => Key::Up,
+        b"B" => Key::Down,
+        b"C" => Key::Right,
+        b"D" => Key::Left,
+        b"H" | b"1~" | b"7~" => Key::Home,
+        b"F" | b"4~" | b"8~" => Key::End,
+        b"5~" => Key::PageUp,
+        b"6~" => Key::PageDown,
+        _ => Key::Unknown,
+    }
+}
+
+fn read_utf8_char(first: u8) -> Key {
+    let length = match first {
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF7 => 4,
+        _ => return Key::Unknown,
+    };
+    let mut bytes = vec![first];
+    for _ in 1..length {
+        match read_byte(Some(25)) {
+            Some(byte) => bytes.push(byte),
+            None => return Key::Unknown,
+        }
+    }
+    match std::str::from_utf8(&bytes) {
+        Ok(text) => text.chars().next().map(Key::Char).unwrap_or(Key::Unknown),
+        Err(_) => Key::Unknown,
+    }
+}
+
+/// Moves the cursor up over `lines` previously printed lines and clears everything below it.
+pub(crate) fn erase_lines(lines: usize) {
+    if !escapes_enabled() {
+        return;
+    }
+    output::uncount_prompt_lines(lines);
+    let mut output = PromptWriter;
+    if lines > 0 {
+        let _ = write!(output, "\x1b[{}A", lines);
+    }
+    let _ = write!(output, "\r\x1b[J");
+    let _ = output.flush();
+}
+
+/// Reads a line of text in place while in raw mode, echoing it after `prompt`. Returns None if the user pressed Escape.
+pub(crate) fn read_inline(raw_mode: &RawMode, prompt: &str, initial: &str) -> Option<String> {
+    let mut output = PromptWriter;
+    let mut buffer = String::from(initial);
+    loop {
+        let _ = write!(output, "\r\x1b[K{}{}", prompt, buffer);
+        let _ = output.flush();
+        match read_key() {
+            Key::Enter => break,
+            Key::Escape => {
+                let _ = writeln!(output);
+                return None;
+            }
+            Key::Backspace => {
+                buffer.pop();
+            }
+            Key::CtrlC => interrupt(raw_mode),
+            Key::Char(c) if !c.is_control() => buffer.push(c),
+            _ => {}
+        }
+    }
+    let _ = writeln!(output);
+    Some(buffer)
+}
+
+/// Clears the visible screen and moves the cursor to the top left corner, without touching the terminal's scrollback.
+pub(crate) fn clear_screen() {
+    if !escapes_enabled() {
+        return;
+    }
+    let mut output = PromptWriter;
+    let _ = write!(output, "\x1b[2J\x1b[H");
+    let _ = output.flush();
+}
+
+static ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(false);
+
+/// Switches the terminal to its alternate screen for as long as the guard is alive, and back to the previous contents when dropped.
+pub(crate) struct AlternateScreen;
+
+impl AlternateScreen {
+    /// Switches to the alternate screen, returning None if prompts are not written to a terminal.
+    pub(crate) fn enter() -> Option<AlternateScreen> {
+        if !escapes_enabled() || ALTERNATE_SCREEN.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+        let mut output = PromptWriter;
+        let _ = write!(output, "\x1b[?1049h\x1b[H");
+        let _ = output.flush();
+        Some(AlternateScreen)
+    }
+}
+
+impl Drop for AlternateScreen {
+    fn drop(&mut self) {
+        leave_alternate_screen();
+    }
+}
+
+fn leave_alternate_screen() {
+    if ALTERNATE_SCREEN.swap(false, Ordering::SeqCst) {
+        let mut output = PromptWriter;
+        let _ = write!(output, "\x1b[?1049l");
+        let _ = output.flush();
+    }
+}
+
+/// Restores the terminal and exits the process, mirroring what Ctrl-C would do outside of raw mode.
+pub(crate) fn interrupt(raw_mode: &RawMode) -> ! {
+    raw_mode.restore();
+    leave_alternate_screen();
+    prompt_println!();
+    std::process::exit(130);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_escape_sequence() {
+        assert_eq!(parse_escape_sequence(b"A"), Key::Up);
+        assert_eq!(parse_escape_sequence(b"B"), Key::Down);
+        assert_eq!(parse_escape_sequence(b"5~"), Key::PageUp);
+        assert_eq!(parse_escape_sequence(b"6~"), Key::PageDown);
+        assert_eq!(parse_escape_sequence(b"H"), Key::Home);
+        assert_eq!(parse_escape_sequence(b"4~"), Key::End);
+        assert_eq!(parse_escape_sequence(b"99~"), Key::Unknown);
+    }
+}
@@ -0,0 +1,795 @@
+use std::{
+    collections::VecDeque,
+    fmt::Display,
+    io::{self, BufRead},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+
+use crate::{
+    count_lines, matches_filter, messages, output,
+    pager::{self, Pager, PagerCommands},
+    print_header, print_prompt, term, theme, PageSize, PagerCommand, PagerScreen, Paginator,
+};
+
+/// A screen line of wrapped text, along with the number of the line it came from, starting from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ScreenLine {
+    line_number: usize,
+    text: String,
+}
+
+/// Splits a line into screen lines no wider than `width` characters, expanding tabs to four spaces.
+fn wrap_line(line: &str, line_number: usize, width: usize) -> Vec<ScreenLine> {
+    let chars: Vec<char> = line.replace('\t', "    ").chars().collect();
+    if chars.is_empty() {
+        return vec![ScreenLine {
+            line_number,
+            text: String::new(),
+        }];
+    }
+    chars
+        .chunks(width.max(1))
+        .map(|chunk| ScreenLine {
+            line_number,
+            text: chunk.iter().collect(),
+        })
+        .collect()
+}
+
+/// Reads the next line from a reader without its line ending, replacing bytes that are not valid UTF-8 with `�`. Returns None once all lines have been read.
+fn read_lossy_line<R: BufRead>(reader: &mut R) -> io::Result<Option<String>> {
+    let mut bytes = Vec::new();
+    if reader.read_until(b'\n', &mut bytes)? == 0 {
+        return Ok(None);
+    }
+    if bytes.ends_with(b"\n") {
+        bytes.pop();
+        if bytes.ends_with(b"\r") {
+            bytes.pop();
+        }
+    }
+    Ok(Some(String::from_utf8_lossy(&bytes).into_owned()))
+}
+
+/// Returns the index of the first screen line at or after `from` which contains `term`, wrapping around to the start of the text.
+fn find_match(screen_lines: &[ScreenLine], term: &str, from: usize) -> Option<usize> {
+    let count = screen_lines.len();
+    (0..count)
+        .map(|offset| (from + offset) % count)
+        .find(|&i| matches_filter(&screen_lines[i].text, term))
+}
+
+/// Styles each occurrence of `term` in `line` with `highlight`.
+fn highlight_matches(line: &str, term: &str, highlight: impl Fn(String) -> String) -> String {
+    let case_sensitive = term.chars().any(char::is_uppercase);
+    let normalize = |c: char| {
+        if case_sensitive {
+            c
+        } else {
+            c.to_lowercase().next().unwrap_or(c)
+        }
+    };
+    let chars: Vec<char> = line.chars().collect();
+    let term: Vec<char> = term.chars().map(normalize).collect();
+    if term.is_empty() {
+        return line.to_string();
+    }
+    let mut highlighted = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let is_match = i + term.len() <= chars.len()
+            && chars[i..i + term.len()]
+                .iter()
+                .zip(term.iter())
+                .all(|(c, t)| normalize(*c) == *t);
+        if is_match {
+            highlighted.push_str(&highlight(chars[i..i + term.len()].iter().collect()));
+            i += term.len();
+        } else {
+            highlighted.push(chars[i]);
+            i += 1;
+        }
+    }
+    highlighted
+}
+
+/// Displays long text one screen at a time, like the `less` command. Lines are wrapped to the terminal's width, and the text is paginated by screen lines.
+///
+/// Navigation works the same as in `paginated_list`. In addition, the user can enter / followed by a search term to jump to the next page containing the term, / on its own to repeat the last search, and : followed by a line number to jump to that line.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the pager.
+/// * `text` - The text to display.
+/// * `lines_per_page` - The number of screen lines that will be displayed per page, or `PageSize::Auto` to fit the page to the terminal's height.
+/// * `clear_on_update` - A boolean which denotes whether the terminal should clear each time the user navigates to a new page. Pass `PagerScreen::AlternateScreen` instead to show the pages on the alternate screen and restore the terminal's previous contents on exit.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// let help = "Usage: mytool [OPTIONS]\n\nOptions:\n  -h, --help  Print help";
+/// page_text(Some("mytool help"), help, PageSize::Auto(20), true);
+/// ```
+pub fn page_text(
+    header_message: Option<&str>,
+    text: &str,
+    lines_per_page: impl Into<PageSize>,
+    clear_on_update: impl Into<PagerScreen>,
+) {
+    let mut pager = TextPager::new(text.lines().map(String::from).collect(), None::<io::Empty>);
+    pager::run_pager(
+        header_message,
+        &mut pager,
+        lines_per_page.into(),
+        clear_on_update.into(),
+    );
+}
+
+/// Reads lines from a reader, such as a file or the output of a command, and displays them with the same pager as `page_text`. Lines are only read as they are needed to fill the pages the user views, so large files open immediately. Bytes that are not valid UTF-8 are replaced with `�`. Panics if reading fails.
+///
+/// Until the end of the text has been read, the number of pages and lines is shown as "?", and jumping to the last page only goes as far as the lines read so far. Searching and jumping to a line read as far as needed.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the pager.
+/// * `reader` - The reader to read the text from.
+/// * `lines_per_page` - The number of screen lines that will be displayed per page, or `PageSize::Auto` to fit the page to the terminal's height.
+/// * `clear_on_update` - A boolean which denotes whether the terminal should clear each time the user navigates to a new page. Pass `PagerScreen::AlternateScreen` instead to show the pages on the alternate screen and restore the terminal's previous contents on exit.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// use std::{fs::File, io::BufReader};
+/// let file = BufReader::new(File::open("build.log").unwrap());
+/// page_reader(Some("build.log"), file, PageSize::Auto(20), true);
+/// ```
+pub fn page_reader<R: BufRead>(
+    header_message: Option<&str>,
+    reader: R,
+    lines_per_page: impl Into<PageSize>,
+    clear_on_update: impl Into<PagerScreen>,
+) {
+    let mut pager = TextPager::new(Vec::new(), Some(reader));
+    pager::run_pager(
+        header_message,
+        &mut pager,
+        lines_per_page.into(),
+        clear_on_update.into(),
+    );
+}
+
+/// The pager behind `page_text` and `page_reader`, which keeps the lines read so far wrapped to the terminal's width. The reader is dropped once all of its lines have been read.
+struct TextPager<R: BufRead> {
+    lines: Vec<String>,
+    reader: Option<R>,
+    width: usize,
+    screen_lines: Vec<ScreenLine>,
+    search: Option<String>,
+    status: Option<String>,
+}
+
+impl<R: BufRead> TextPager<R> {
+    fn new(lines: Vec<String>, reader: Option<R>) -> TextPager<R> {
+        TextPager {
+            lines,
+            reader,
+            width: 0,
+            screen_lines: Vec::new(),
+            search: None,
+            status: None,
+        }
+    }
+
+    /// Reads the next line from the reader and wraps it. Returns false if all lines have been read.
+    fn read_line(&mut self) -> bool {
+        let reader = match self.reader.as_mut() {
+            Some(reader) => reader,
+            None => return false,
+        };
+        let line = match read_lossy_line(reader) {
+            Ok(Some(line)) => line,
+            Ok(None) => {
+                self.reader = None;
+                return false;
+            }
+            Err(error) => panic!("Unexpected error while reading text: {}", error),
+        };
+        self.screen_lines
+            .extend(wrap_line(&line, self.lines.len() + 1, self.width));
+        self.lines.push(line);
+        true
+    }
+
+    /// Reads lines until there are at least `count` screen lines, or all lines have been read.
+    fn read_screen_lines(&mut self, count: usize) {
+        while self.screen_lines.len() < count && self.read_line() {}
+    }
+
+    /// Returns the index of the first screen line at or after `from` which contains `term`, reading lines as needed, and wrapping around to the start of the text.
+    fn find(&mut self, term: &str, from: usize) -> Option<usize> {
+        let mut checked = from;
+        loop {
+            if let Some(index) = (checked..self.screen_lines.len())
+                .find(|&i| matches_filter(&self.screen_lines[i].text, term))
+            {
+                return Some(index);
+            }
+            checked = self.screen_lines.len().max(from);
+            if !self.read_line() {
+                return find_match(&self.screen_lines, term, from);
+            }
+        }
+    }
+
+    /// Moves to the page containing a screen line.
+    fn show_line(paginator: &mut Paginator, index: usize) {
+        paginator.goto(index / paginator.page_size() + 1);
+    }
+}
+
+impl<R: BufRead> Pager for TextPager<R> {
+    type Output = ();
+
+    fn paginator(&mut self, page_size: usize) -> Paginator {
+        match self.reader {
+            Some(_) => Paginator::unbounded(page_size),
+            None => Paginator::new(0, page_size),
+        }
+    }
+
+    fn commands(&self) -> PagerCommands {
+        PagerCommands {
+            search: true,
+            lines: true,
+            ..PagerCommands::default()
+        }
+    }
+
+    fn prepare(&mut self, paginator: &mut Paginator) {
+        let terminal_width = term::width();
+        if terminal_width != self.width {
+            let top_line = self
+                .screen_lines
+                .get(paginator.page_range().start)
+                .map_or(1, |screen_line| screen_line.line_number);
+            self.width = terminal_width;
+            self.screen_lines = self
+                .lines
+                .iter()
+                .enumerate()
+                .flat_map(|(i, line)| wrap_line(line, i + 1, self.width))
+                .collect();
+            if self.reader.is_none() {
+                paginator.set_item_count(self.screen_lines.len());
+            }
+            let top = self
+                .screen_lines
+                .iter()
+                .position(|screen_line| screen_line.line_number >= top_line)
+                .unwrap_or(0);
+            TextPager::<R>::show_line(paginator, top);
+        }
+        self.read_screen_lines(paginator.page_range().end.saturating_add(1));
+        if self.reader.is_none() && !paginator.total_known() {
+            paginator.set_item_count(self.screen_lines.len());
+        }
+    }
+
+    fn page_lines(&mut self, paginator: &Paginator) -> Vec<String> {
+        paginator
+            .page_items(&self.screen_lines)
+            .iter()
+            .map(|screen_line| match &self.search {
+                Some(term) => highlight_matches(&screen_line.text, term, theme::highlight),
+                _ => screen_line.text.clone(),
+            })
+            .collect()
+    }
+
+    fn footer(&self, paginator: &Paginator) -> String {
+        let range = paginator.page_range();
+        let line_number = |index: usize| self.screen_lines.get(index).map_or(0, |l| l.line_number);
+        let (pages, total) = if paginator.total_known() {
+            (
+                paginator.page_count().to_string(),
+                self.lines.len().to_string(),
+            )
+        } else {
+            (String::from("?"), String::from("?"))
+        };
+        messages::message(
+            |m| &m.text_page_footer,
+            &[
+                ("page", &paginator.current_page()),
+                ("pages", &pages),
+                ("first", &line_number(range.start)),
+                ("last", &line_number(range.end.saturating_sub(1))),
+                ("total", &total),
+            ],
+        )
+    }
+
+    fn status(&mut self) -> Option<String> {
+        self.status.take()
+    }
+
+    fn key_hint(&self) -> String {
+        messages::message(|m| &m.text_keys, &[])
+    }
+
+    fn line_help(&self) -> String {
+        messages::message(|m| &m.text_help, &[])
+    }
+
+    fn search_prompt(&self) -> String {
+        messages::message(|m| &m.text_search_prompt, &[])
+    }
+
+    fn handle(&mut self, command: PagerCommand, paginator: &mut Paginator) -> Option<()> {
+        match command {
+            PagerCommand::GoToLine(line_number) => {
+                while self.lines.len() < line_number && self.read_line() {}
+                match self
+                    .screen_lines
+                    .iter()
+                    .position(|screen_line| screen_line.line_number >= line_number.max(1))
+                {
+                    Some(index) => TextPager::<R>::show_line(paginator, index),
+                    None => {
+                        self.status = Some(messages::message(
+                            |m| &m.no_such_line,
+                            &[("input", &line_number)],
+                        ))
+                    }
+                }
+            }
+            PagerCommand::Filter(term) => {
+                let term = term.unwrap_or_default();
+                let repeat = term.is_empty();
+                if !repeat {
+                    self.search = Some(term);
+                }
+                if let Some(term) = self.search.clone() {
+                    let range = paginator.page_range();
+                    let from = if repeat { range.end } else { range.start };
+                    match self.find(&term, from) {
+                        Some(index) => TextPager::<R>::show_line(paginator, index),
+                        None => {
+                            self.status = Some(messages::message(
+                                |m| &m.no_matching_lines,
+                                &[("input", &term)],
+                            ))
+                        }
+                    }
+                }
+            }
+            PagerCommand::ClearFilter => self.search = None,
+            _ => {}
+        }
+        None
+    }
+}
+
+/// How long follow mode waits for a keypress before checking for new lines, in milliseconds.
+const FOLLOW_POLL_MS: i32 = 100;
+
+/// The number of most recent lines kept by `follow_text`. Older lines are dropped and can no longer be scrolled back to.
+const FOLLOW_MAX_LINES: usize = 10_000;
+
+/// The lines received by `follow_text`, keeping only the most recent `capacity` lines along with their screen lines.
+struct FollowBuffer {
+    lines: VecDeque<(usize, String)>,
+    screen_lines: VecDeque<ScreenLine>,
+    width: usize,
+    capacity: usize,
+}
+
+impl FollowBuffer {
+    fn new(capacity: usize) -> FollowBuffer {
+        FollowBuffer {
+            lines: VecDeque::new(),
+            screen_lines: VecDeque::new(),
+            width: 0,
+            capacity,
+        }
+    }
+
+    /// Returns the number of the last line received, which is the total number of lines received so far.
+    fn line_count(&self) -> usize {
+        self.lines.back().map_or(0, |(line_number, _)| *line_number)
+    }
+
+    /// Wraps the kept lines to a new width. Returns false if the width is unchanged.
+    fn set_width(&mut self, width: usize) -> bool {
+        if width == self.width {
+            return false;
+        }
+        self.width = width;
+        self.screen_lines = self
+            .lines
+            .iter()
+            .flat_map(|(line_number, line)| wrap_line(line, *line_number, width))
+            .collect();
+        true
+    }
+
+    /// Adds a received line, dropping the oldest line once the buffer is full. Returns the number of screen lines dropped.
+    fn push(&mut self, line: String) -> usize {
+        let line_number = self.line_count() + 1;
+        self.screen_lines
+            .extend(wrap_line(&line, line_number, self.width));
+        self.lines.push_back((line_number, line));
+        if self.lines.len() <= self.capacity {
+            return 0;
+        }
+        self.lines.pop_front();
+        let first = self
+            .lines
+            .front()
+            .map_or(0, |(line_number, _)| *line_number);
+        let mut dropped = 0;
+        while self
+            .screen_lines
+            .front()
+            .is_some_and(|screen_line| screen_line.line_number < first)
+        {
+            self.screen_lines.pop_front();
+            dropped += 1;
+        }
+        dropped
+    }
+}
+
+/// The position of the view in follow mode, as the index of the top screen line shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FollowView {
+    top: usize,
+    following: bool,
+}
+
+impl FollowView {
+    fn bottom(line_count: usize, height: usize) -> usize {
+        line_count.saturating_sub(height)
+    }
+
+    /// Keeps the view pinned to the newest lines while following, and within the text otherwise.
+    fn update(&mut self, line_count: usize, height: usize) {
+        let bottom = FollowView::bottom(line_count, height);
+        if self.following {
+            self.top = bottom;
+        } else {
+            self.top = self.top.min(bottom);
+        }
+    }
+
+    /// Scrolls the view by a number of lines. Scrolling up pauses following, and scrolling down to the newest lines resumes it.
+    fn scroll(&mut self, offset: isize, line_count: usize, height: usize) {
+        let bottom = FollowView::bottom(line_count, height);
+        self.top = self.top.saturating_add_signed(offset).min(bottom);
+        if offset < 0 {
+            self.following = false;
+        } else if self.top == bottom {
+            self.following = true;
+        }
+    }
+}
+
+/// Displays lines of text as they are received, keeping the view pinned to the newest lines, like `tail -f`. This is useful for following the output of a long-running process, such as a build log.
+///
+/// When attached to a terminal, scrolling up with the up arrow, Page Up or Home pauses following so earlier lines can be read, and pressing F or End resumes it. Only the most recent 10,000 lines are kept for scrolling back. Q exits. Otherwise, lines are printed as they are received until the sender is dropped.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the pager.
+/// * `receiver` - The receiving end of a channel which the lines are sent through.
+/// * `lines_per_page` - The number of screen lines that will be displayed, or `PageSize::Auto` to fit the view to the terminal's height.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// use std::{sync::mpsc, thread, time::Duration};
+/// let (sender, receiver) = mpsc::channel();
+/// thread::spawn(move || {
+///     for step in 1..=100 {
+///         sender.send(format!("Compiling step {}", step)).unwrap();
+///         thread::sleep(Duration::from_millis(200));
+///     }
+/// });
+/// follow_text(Some("Build log:"), receiver, PageSize::Auto(20));
+/// ```
+pub fn follow_text(
+    header_message: Option<&str>,
+    receiver: Receiver<String>,
+    lines_per_page: impl Into<PageSize>,
+) {
+    let raw_mode = match term::RawMode::enable() {
+        Some(raw_mode) => raw_mode,
+        None => {
+            print_header(header_message);
+            for line in receiver.iter() {
+                println!("{}", line);
+            }
+            return;
+        }
+    };
+    let page_size = lines_per_page.into();
+    let reserved_lines = header_message.map_or(0, count_lines) + 2;
+    let mut buffer = FollowBuffer::new(FOLLOW_MAX_LINES);
+    let mut view = FollowView {
+        top: 0,
+        following: true,
+    };
+    let mut new_lines = 0;
+    let mut finished = false;
+    let mut lines_to_erase = 0;
+    let mut changed = true;
+    loop {
+        changed |= buffer.set_width(term::width());
+        loop {
+            match receiver.try_recv() {
+                Ok(line) => {
+                    let dropped = buffer.push(line);
+                    view.top = view.top.saturating_sub(dropped);
+                    if !view.following {
+                        new_lines += 1;
+                    }
+                    changed = true;
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    changed |= !finished;
+                    finished = true;
+                    break;
+                }
+            }
+        }
+        let height = page_size.resolve(reserved_lines);
+        if changed {
+            let screen_lines = &buffer.screen_lines;
+            view.update(screen_lines.len(), height);
+            if lines_to_erase > 0 {
+                term::erase_lines(lines_to_erase);
+            }
+            output::begin_prompt();
+            let mut lines = 0;
+            if print_prompt(header_message) {
+                lines += count_lines(header_message.unwrap_or_default());
+            }
+            let end = (view.top + height).min(screen_lines.len());
+            for screen_line in screen_lines.range(view.top..end) {
+                prompt_println!("{}", screen_line.text);
+                lines += 1;
+            }
+            let state = if view.following {
+                messages::message(|m| &m.follow_following, &[])
+            } else if new_lines > 0 {
+                messages::message(|m| &m.follow_paused_new_lines, &[("count", &new_lines)])
+            } else {
+                messages::message(|m| &m.follow_paused, &[])
+            };
+            let values: [(&str, &dyn Display); 4] = [
+                ("state", &state),
+                (
+                    "first",
+                    &screen_lines.get(view.top).map_or(0, |l| l.line_number),
+                ),
+                (
+                    "last",
+                    &screen_lines
+                        .get(end.saturating_sub(1))
+                        .map_or(0, |l| l.line_number),
+                ),
+                ("total", &buffer.line_count()),
+            ];
+            let footer = if finished {
+                messages::message(|m| &m.follow_footer_finished, &values)
+            } else {
+                messages::message(|m| &m.follow_footer, &values)
+            };
+            let footer = format!(
+                "{}  {}",
+                theme::footer(footer),
+                theme::hint(messages::message(|m| &m.follow_keys, &[]))
+            );
+            prompt_println!("{}", footer);
+            lines += count_lines(&footer);
+            lines_to_erase = lines;
+            changed = false;
+        }
+        let key = match term::poll_key(FOLLOW_POLL_MS) {
+            Some(key) => key,
+            None => continue,
+        };
+        let line_count = buffer.screen_lines.len();
+        match key {
+            term::Key::Up | term::Key::Char('k') => view.scroll(-1, line_count, height),
+            term::Key::Down | term::Key::Char('j') => view.scroll(1, line_count, height),
+            term::Key::PageUp | term::Key::Char('p') | term::Key::Char('b') => {
+                view.scroll(-(height as isize), line_count, height)
+            }
+            term::Key::PageDown | term::Key::Char('n') | term::Key::Char(' ') => {
+                view.scroll(height as isize, line_count, height)
+            }
+            term::Key::Home | term::Key::Char('g') => view.scroll(isize::MIN, line_count, height),
+            term::Key::End | term::Key::Char('G') | term::Key::Char('f') | term::Key::Char('F') => {
+                view.following = true;
+            }
+            term::Key::Char('q') | term::Key::Char('Q') | term::Key::Escape => return,
+            term::Key::CtrlC => term::interrupt(&raw_mode),
+            _ => {}
+        }
+        if view.following {
+            new_lines = 0;
+        }
+        changed = true;
+    }
+}
+
+/// Reads lines from a reader on a background thread and displays them with `follow_text` as they arrive, such as the output of a running command. Bytes that are not valid UTF-8 are replaced with `�`, and if reading fails, the error is shown as the last line.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the pager.
+/// * `reader` - The reader to read lines from.
+/// * `lines_per_page` - The number of screen lines that will be displayed, or `PageSize::Auto` to fit the view to the terminal's height.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+/// use std::{io::BufReader, process::{Command, Stdio}};
+/// let child = Command::new("cargo").arg("build").stderr(Stdio::piped()).spawn().unwrap();
+/// follow_reader(Some("cargo build"), BufReader::new(child.stderr.unwrap()), PageSize::Auto(20));
+/// ```
+pub fn follow_reader<R: BufRead + Send + 'static>(
+    header_message: Option<&str>,
+    mut reader: R,
+    lines_per_page: impl Into<PageSize>,
+) {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || loop {
+        let line = match read_lossy_line(&mut reader) {
+            Ok(Some(line)) => line,
+            Ok(None) => break,
+            Err(error) => {
+                let _ = sender.send(messages::message(
+                    |m| &m.follow_read_error,
+                    &[("error", &error)],
+                ));
+                break;
+            }
+        };
+        if sender.send(line).is_err() {
+            break;
+        }
+    });
+    follow_text(header_message, receiver, lines_per_page);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Theme;
+
+    /// Splits text into screen lines no wider than `width` characters, expanding tabs to four spaces.
+    fn wrap_text(text: &str, width: usize) -> Vec<ScreenLine> {
+        text.lines()
+            .enumerate()
+            .flat_map(|(i, line)| wrap_line(line, i + 1, width))
+            .collect()
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        let screen_lines = wrap_text("abcdef\n\nxy", 4);
+        let texts: Vec<&str> = screen_lines.iter().map(|l| l.text.as_str()).collect();
+        let line_numbers: Vec<usize> = screen_lines.iter().map(|l| l.line_number).collect();
+        assert_eq!(texts, vec!["abcd", "ef", "", "xy"]);
+        assert_eq!(line_numbers, vec![1, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_text_pager_reading() {
+        let reader = io::Cursor::new(b"one\r\nt\xffo\nthree\nerror four\n".to_vec());
+        let mut pager = TextPager::new(Vec::new(), Some(reader));
+        pager.width = 80;
+        pager.read_screen_lines(2);
+        assert_eq!(pager.lines, vec!["one", "t\u{fffd}o"]);
+        assert_eq!(pager.find("error", 0), Some(3));
+        assert!(pager.reader.is_some());
+        assert_eq!(pager.find("one", 1), Some(0));
+        assert!(pager.reader.is_none());
+        assert_eq!(pager.lines.len(), 4);
+    }
+
+    #[test]
+    fn test_text_pager_last_page() {
+        let reader = io::Cursor::new(b"one\ntwo\n".to_vec());
+        let mut pager = TextPager::new(Vec::new(), Some(reader));
+        let mut paginator = pager.paginator(15);
+        assert!(paginator.goto(usize::MAX / 15));
+        assert_eq!(paginator.page_range().end, usize::MAX);
+        pager.prepare(&mut paginator);
+        assert!(paginator.total_known());
+        assert_eq!(paginator.current_page(), 1);
+    }
+
+    #[test]
+    fn test_find_match() {
+        let screen_lines = wrap_text("error one\nok\nError two", 80);
+        assert_eq!(find_match(&screen_lines, "error", 0), Some(0));
+        assert_eq!(find_match(&screen_lines, "error", 1), Some(2));
+        assert_eq!(find_match(&screen_lines, "Error", 0), Some(2));
+        assert_eq!(find_match(&screen_lines, "one", 1), Some(0));
+        assert_eq!(find_match(&screen_lines, "missing", 0), None);
+    }
+
+    #[test]
+    fn test_highlight_matches() {
+        let highlight = |text: String| Theme::colored().highlight.paint(text);
+        assert_eq!(
+            highlight_matches("An Error and an error", "error", highlight),
+            "An \x1b[7mError\x1b[0m and an \x1b[7merror\x1b[0m"
+        );
+        assert_eq!(
+            highlight_matches("An Error and an error", "Error", highlight),
+            "An \x1b[7mError\x1b[0m and an error"
+        );
+        assert_eq!(
+            highlight_matches("An Error", "error", |text| Theme::plain()
+                .highlight
+                .paint(text)),
+            "An Error"
+        );
+    }
+
+    #[test]
+    fn test_follow_view() {
+        let mut view = FollowView {
+            top: 0,
+            following: true,
+        };
+        view.update(25, 10);
+        assert_eq!(view.top, 15);
+        view.scroll(-5, 25, 10);
+        assert_eq!(
+            view,
+            FollowView {
+                top: 10,
+                following: false
+            }
+        );
+        view.update(40, 10);
+        assert_eq!(view.top, 10);
+        view.scroll(100, 40, 10);
+        assert_eq!(
+            view,
+            FollowView {
+                top: 30,
+                following: true
+            }
+        );
+    }
+
+    #[test]
+    fn test_follow_buffer() {
+        let mut buffer = FollowBuffer::new(3);
+        buffer.set_width(4);
+        assert_eq!(buffer.push("abcdef".to_string()), 0);
+        assert_eq!(buffer.push("x".to_string()), 0);
+        assert_eq!(buffer.push("y".to_string()), 0);
+        assert_eq!(buffer.push("z".to_string()), 2);
+        assert_eq!(buffer.line_count(), 4);
+        let line_numbers: Vec<usize> = buffer.screen_lines.iter().map(|l| l.line_number).collect();
+        assert_eq!(line_numbers, vec![2, 3, 4]);
+        assert!(buffer.set_width(1));
+        assert!(!buffer.set_width(1));
+        assert_eq!(buffer.screen_lines.len(), 3);
+        assert_eq!(buffer.push("long".to_string()), 1);
+        assert_eq!(buffer.screen_lines.len(), 6);
+    }
+}
@@ -0,0 +1,296 @@
+use std::{fmt::Display, sync::Mutex};
+
+use crate::term;
+
+/// One of the 16 standard terminal colors, which terminals display according to their own color scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl Color {
+    fn foreground_code(&self) -> u8 {
+        match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::BrightBlack => 90,
+            Color::BrightRed => 91,
+            Color::BrightGreen => 92,
+            Color::BrightYellow => 93,
+            Color::BrightBlue => 94,
+            Color::BrightMagenta => 95,
+            Color::BrightCyan => 96,
+            Color::BrightWhite => 97,
+        }
+    }
+}
+
+/// The color and text attributes used to display a kind of text, such as error messages.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// let warning = Style::new().fg(Color::Yellow).bold();
+/// assert_eq!(warning.paint("Careful!"), "\x1b[1;33mCareful!\x1b[0m");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    foreground: Option<Color>,
+    background: Option<Color>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    reverse: bool,
+}
+
+impl Style {
+    /// Creates a style which displays text unchanged.
+    pub const fn new() -> Style {
+        Style {
+            foreground: None,
+            background: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            reverse: false,
+        }
+    }
+
+    /// Sets the color of the text.
+    pub const fn fg(mut self, color: Color) -> Style {
+        self.foreground = Some(color);
+        self
+    }
+
+    /// Sets the color behind the text.
+    pub const fn bg(mut self, color: Color) -> Style {
+        self.background = Some(color);
+        self
+    }
+
+    /// Makes the text bold.
+    pub const fn bold(mut self) -> Style {
+        self.bold = true;
+        self
+    }
+
+    /// Makes the text dim.
+    pub const fn dim(mut self) -> Style {
+        self.dim = true;
+        self
+    }
+
+    /// Makes the text italic, in terminals that support it.
+    pub const fn italic(mut self) -> Style {
+        self.italic = true;
+        self
+    }
+
+    /// Underlines the text.
+    pub const fn underline(mut self) -> Style {
+        self.underline = true;
+        self
+    }
+
+    /// Swaps the colors of the text and its background.
+    pub const fn reverse(mut self) -> Style {
+        self.reverse = true;
+        self
+    }
+
+    /// Returns the text wrapped in the escape sequences for the style, whether or not colors are enabled.
+    pub fn paint(&self, text: impl Display) -> String {
+        let mut codes: Vec<String> = Vec::new();
+        for (enabled, code) in [
+            (self.bold, 1),
+            (self.dim, 2),
+            (self.italic, 3),
+            (self.underline, 4),
+            (self.reverse, 7),
+        ] {
+            if enabled {
+                codes.push(code.to_string());
+            }
+        }
+        if let Some(color) = self.foreground {
+            codes.push(color.foreground_code().to_string());
+        }
+        if let Some(color) = self.background {
+            codes.push((color.foreground_code() + 10).to_string());
+        }
+        if codes.is_empty() {
+            return text.to_string();
+        }
+        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+    }
+}
+
+/// The styles used for each kind of text the library displays. Set the theme used with `set_theme`.
+///
+/// Text is displayed unchanged until a theme is set. `Theme::colored` is a ready-made theme to start from.
+///
+/// Colors are only shown when prompts are written to a terminal, and can be turned off by setting the `NO_COLOR` environment variable, or forced on by setting `CLICOLOR_FORCE` to anything other than 0.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+/// set_theme(Theme {
+///     error: Style::new().fg(Color::Magenta).bold(),
+///     ..Theme::colored()
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// The style of prompts and header messages.
+    pub prompt: Style,
+    /// The style of hints about which keys or commands are available.
+    pub hint: Style,
+    /// The style of error messages, such as when the input is not a valid choice.
+    pub error: Style,
+    /// The style of selected items, such as the checked items of a checklist.
+    pub selected: Style,
+    /// The style of the page counter below the items of a pager.
+    pub footer: Style,
+    /// The style of the text matching a search, such as the matched characters of a fuzzy selection or the matches of a search in a text pager.
+    pub highlight: Style,
+}
+
+impl Theme {
+    /// Returns a theme which displays all text unchanged.
+    pub const fn plain() -> Theme {
+        Theme {
+            prompt: Style::new(),
+            hint: Style::new(),
+            error: Style::new(),
+            selected: Style::new(),
+            footer: Style::new(),
+            highlight: Style::new(),
+        }
+    }
+
+    /// Returns a theme which shows prompts in bold, hints dimmed, errors in red, selected items in bold green, pager footers in cyan and search matches in reverse video.
+    pub const fn colored() -> Theme {
+        Theme {
+            prompt: Style::new().bold(),
+            hint: Style::new().dim(),
+            error: Style::new().fg(Color::Red),
+            selected: Style::new().fg(Color::Green).bold(),
+            footer: Style::new().fg(Color::Cyan),
+            highlight: Style::new().reverse(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::plain()
+    }
+}
+
+static THEME: Mutex<Option<Theme>> = Mutex::new(None);
+
+/// Sets the theme used to style prompts, hints, error messages, selected items, pager footers and search matches.
+pub fn set_theme(theme: Theme) {
+    *THEME.lock().unwrap() = Some(theme);
+}
+
+/// Returns the theme set with `set_theme`, or the plain default theme if none was set.
+pub fn theme() -> Theme {
+    THEME.lock().unwrap().unwrap_or_default()
+}
+
+/// Returns true if styles should be applied: colors are forced on by `CLICOLOR_FORCE`, or prompts are written to a terminal and `NO_COLOR` is not set.
+fn colors_enabled() -> bool {
+    let env_set = |name: &str| std::env::var_os(name).is_some_and(|value| !value.is_empty());
+    if env_set("NO_COLOR") {
+        return false;
+    }
+    if std::env::var_os("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0") {
+        return true;
+    }
+    term::escapes_enabled()
+}
+
+fn styled(text: impl Display, style: fn(&Theme) -> Style) -> String {
+    if colors_enabled() {
+        style(&theme()).paint(text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Styles a prompt or header message with the current theme.
+pub(crate) fn prompt(text: impl Display) -> String {
+    styled(text, |theme| theme.prompt)
+}
+
+/// Styles a hint with the current theme.
+pub(crate) fn hint(text: impl Display) -> String {
+    styled(text, |theme| theme.hint)
+}
+
+/// Styles an error message with the current theme.
+pub(crate) fn error(text: impl Display) -> String {
+    styled(text, |theme| theme.error)
+}
+
+/// Styles a selected item with the current theme.
+pub(crate) fn selected(text: impl Display) -> String {
+    styled(text, |theme| theme.selected)
+}
+
+/// Styles a pager footer with the current theme.
+pub(crate) fn footer(text: impl Display) -> String {
+    styled(text, |theme| theme.footer)
+}
+
+/// Styles text matching a search with the current theme.
+pub(crate) fn highlight(text: impl Display) -> String {
+    styled(text, |theme| theme.highlight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_paint() {
+        assert_eq!(Style::new().paint("plain"), "plain");
+        assert_eq!(
+            Style::new()
+                .fg(Color::BrightBlue)
+                .bg(Color::Black)
+                .underline()
+                .paint(42),
+            "\x1b[4;94;40m42\x1b[0m"
+        );
+        assert_eq!(Theme::plain().error.paint("oops"), "oops");
+        assert_eq!(theme(), Theme::plain());
+        assert_eq!(Theme::colored().error.paint("oops"), "\x1b[31moops\x1b[0m");
+    }
+}
@@ -0,0 +1,342 @@
+use std::collections::HashSet;
+
+use crate::{
+    messages, page_footer,
+    pager::{self, Pager},
+    print_header, PageSize, PagerCommand, PagerScreen, Paginator,
+};
+
+/// A node of a tree which can be displayed with `print_tree` or `browse_tree`, such as a directory or a package in a dependency graph.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+///
+/// struct Dir {
+///     name: String,
+///     entries: Vec<Dir>,
+/// }
+///
+/// impl TreeNode for Dir {
+///     fn label(&self) -> String {
+///         self.name.clone()
+///     }
+///
+///     fn children(&self) -> Vec<&Dir> {
+///         self.entries.iter().collect()
+///     }
+/// }
+/// ```
+pub trait TreeNode {
+    /// Returns the text displayed for the node.
+    fn label(&self) -> String;
+
+    /// Returns the node's children, in the order they are displayed.
+    fn children(&self) -> Vec<&Self>;
+}
+
+/// The characters used to draw the guides connecting the nodes of a tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TreeGuides {
+    /// Guides drawn with box-drawing characters, such as `├──` and `└──`.
+    #[default]
+    Unicode,
+    /// Guides drawn with `|`, `-` and `` ` ``, for terminals that can't display box-drawing characters.
+    Ascii,
+}
+
+impl TreeGuides {
+    /// Returns the guides for a child that has siblings below it, the last child, a parent that has siblings below it, and the last parent.
+    fn parts(&self) -> [&'static str; 4] {
+        match self {
+            TreeGuides::Unicode => ["├── ", "└── ", "│   ", "    "],
+            TreeGuides::Ascii => ["|-- ", "`-- ", "|   ", "    "],
+        }
+    }
+}
+
+/// A node of a tree rendered as a line, with the path of child indices leading to it from the root.
+struct TreeLine {
+    text: String,
+    path: Vec<usize>,
+    has_children: bool,
+}
+
+/// Renders the nodes of a tree as lines, descending into the nodes for which `expanded` returns true. Collapsed nodes that have children are marked with the number of hidden children, such as `(+3)`.
+fn tree_lines<N: TreeNode>(
+    root: &N,
+    guides: TreeGuides,
+    expanded: &dyn Fn(&[usize]) -> bool,
+) -> Vec<TreeLine> {
+    let mut lines = Vec::new();
+    add_tree_lines(root, guides, expanded, &mut Vec::new(), "", "", &mut lines);
+    lines
+}
+
+fn add_tree_lines<N: TreeNode>(
+    node: &N,
+    guides: TreeGuides,
+    expanded: &dyn Fn(&[usize]) -> bool,
+    path: &mut Vec<usize>,
+    guide: &str,
+    indent: &str,
+    lines: &mut Vec<TreeLine>,
+) {
+    let children = node.children();
+    let is_expanded = expanded(path);
+    let label = node.label().replace('\n', " ");
+    let text = if children.is_empty() || is_expanded {
+        format!("{}{}", guide, label)
+    } else {
+        format!("{}{} (+{})", guide, label, children.len())
+    };
+    lines.push(TreeLine {
+        text,
+        path: path.clone(),
+        has_children: !children.is_empty(),
+    });
+    if !is_expanded {
+        return;
+    }
+    let [branch, last_branch, pipe, space] = guides.parts();
+    for (i, child) in children.iter().enumerate() {
+        let is_last = i + 1 == children.len();
+        path.push(i);
+        add_tree_lines(
+            *child,
+            guides,
+            expanded,
+            path,
+            &format!("{}{}", indent, if is_last { last_branch } else { branch }),
+            &format!("{}{}", indent, if is_last { space } else { pipe }),
+            lines,
+        );
+        path.pop();
+    }
+}
+
+/// Displays a tree, with guides connecting each node to its parent.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the tree.
+/// * `root` - The root node of the tree.
+/// * `guides` - The characters used to draw the guides between the nodes.
+/// * `max_depth` - An option that can contain the number of levels below the root to display. Nodes whose children are hidden are marked with the number of hidden children, such as `(+3)`.
+///
+/// # Example
+///
+/// ```
+/// use simple_cli::*;
+///
+/// struct Package(&'static str, Vec<Package>);
+///
+/// impl TreeNode for Package {
+///     fn label(&self) -> String {
+///         self.0.to_string()
+///     }
+///
+///     fn children(&self) -> Vec<&Package> {
+///         self.1.iter().collect()
+///     }
+/// }
+///
+/// let root = Package("app", vec![
+///     Package("serde", vec![Package("serde_derive", vec![])]),
+///     Package("libc", vec![]),
+/// ]);
+/// print_tree(Some("Dependencies:"), &root, TreeGuides::Unicode, Some(1));
+/// ```
+pub fn print_tree<N: TreeNode>(
+    header_message: Option<&str>,
+    root: &N,
+    guides: TreeGuides,
+    max_depth: Option<usize>,
+) {
+    print_header(header_message);
+    let expanded = |path: &[usize]| max_depth.is_none_or(|max_depth| path.len() < max_depth);
+    for line in tree_lines(root, guides, &expanded) {
+        println!("{}", line.text);
+    }
+}
+
+/// Displays a tree one page at a time and lets the user expand and collapse its nodes. Only the root is expanded at first. Entering the number of a node on the current page, or its label, expands or collapses it. Navigation works the same as in `paginated_list`, except that jumping to a page number is done by entering S followed by the page number, such as S 4.
+///
+/// # Arguments
+///
+/// * `header_message` - An option that can contain a string slice which holds a header message for the tree.
+/// * `root` - The root node of the tree.
+/// * `guides` - The characters used to draw the guides between the nodes.
+/// * `lines_per_page` - The number of nodes that will be displayed per page, or `PageSize::Auto` to fit the page to the terminal's height.
+/// * `clear_on_update` - A boolean which denotes whether the terminal should clear each time the user navigates to a new page or expands a node. Pass `PagerScreen::AlternateScreen` instead to show the pages on the alternate screen and restore the terminal's previous contents on exit.
+///
+/// # Example
+///
+/// ```no_run
+/// use simple_cli::*;
+///
+/// struct Package(&'static str, Vec<Package>);
+///
+/// impl TreeNode for Package {
+///     fn label(&self) -> String {
+///         self.0.to_string()
+///     }
+///
+///     fn children(&self) -> Vec<&Package> {
+///         self.1.iter().collect()
+///     }
+/// }
+///
+/// let root = Package("app", vec![Package("serde", vec![Package("serde_derive", vec![])])]);
+/// browse_tree(Some("Dependencies:"), &root, TreeGuides::Unicode, PageSize::Auto(20), true);
+/// ```
+pub fn browse_tree<N: TreeNode>(
+    header_message: Option<&str>,
+    root: &N,
+    guides: TreeGuides,
+    lines_per_page: impl Into<PageSize>,
+    clear_on_update: impl Into<PagerScreen>,
+) {
+    let expanded: HashSet<Vec<usize>> = HashSet::from([Vec::new()]);
+    let mut pager = TreePager {
+        root,
+        guides,
+        lines: tree_lines(root, guides, &|path| expanded.contains(path)),
+        expanded,
+    };
+    pager::run_pager(
+        header_message,
+        &mut pager,
+        lines_per_page.into(),
+        clear_on_update.into(),
+    );
+}
+
+/// The pager behind `browse_tree`, which keeps track of the expanded nodes.
+struct TreePager<'a, N: TreeNode> {
+    root: &'a N,
+    guides: TreeGuides,
+    expanded: HashSet<Vec<usize>>,
+    lines: Vec<TreeLine>,
+}
+
+impl<N: TreeNode> Pager for TreePager<'_, N> {
+    type Output = ();
+
+    fn paginator(&mut self, page_size: usize) -> Paginator {
+        Paginator::new(self.lines.len(), page_size)
+    }
+
+    fn page_lines(&mut self, paginator: &Paginator) -> Vec<String> {
+        let page_lines = paginator.page_items(&self.lines);
+        let number_width = page_lines.len().to_string().len();
+        page_lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| format!("{:>width$}. {}", i + 1, line.text, width = number_width))
+            .collect()
+    }
+
+    fn plain_lines(&mut self, paginator: &Paginator) -> Vec<String> {
+        paginator
+            .page_items(&self.lines)
+            .iter()
+            .map(|line| line.text.clone())
+            .collect()
+    }
+
+    fn footer(&self, paginator: &Paginator) -> String {
+        page_footer(paginator.current_page(), paginator.page_count())
+    }
+
+    fn page_labels(&self, paginator: &Paginator) -> Option<Vec<String>> {
+        Some(
+            paginator
+                .page_items(&self.lines)
+                .iter()
+                .map(|line| node_label(self.root, &line.path))
+                .collect(),
+        )
+    }
+
+    fn key_hint(&self) -> String {
+        messages::message(|m| &m.tree_keys, &[])
+    }
+
+    fn line_help(&self) -> String {
+        messages::message(|m| &m.tree_help, &[])
+    }
+
+    fn handle(&mut self, command: PagerCommand, paginator: &mut Paginator) -> Option<()> {
+        if let PagerCommand::Select(index) = command {
+            let line = &paginator.page_items(&self.lines)[index];
+            if line.has_children && !self.expanded.remove(&line.path) {
+                self.expanded.insert(line.path.clone());
+            }
+            self.lines = tree_lines(self.root, self.guides, &|path| self.expanded.contains(path));
+            paginator.set_item_count(self.lines.len());
+        }
+        None
+    }
+}
+
+/// Returns the label of the node at the end of a path of child indices.
+fn node_label<N: TreeNode>(root: &N, path: &[usize]) -> String {
+    let mut node = root;
+    for &i in path {
+        node = node.children()[i];
+    }
+    node.label()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Node(&'static str, Vec<Node>);
+
+    impl TreeNode for Node {
+        fn label(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn children(&self) -> Vec<&Node> {
+            self.1.iter().collect()
+        }
+    }
+
+    fn render(root: &Node, guides: TreeGuides, max_depth: usize) -> Vec<String> {
+        tree_lines(root, guides, &|path| path.len() < max_depth)
+            .into_iter()
+            .map(|line| line.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_tree_lines() {
+        let root = Node(
+            "src",
+            vec![
+                Node("bin", vec![Node("main.rs", vec![]), Node("cli.rs", vec![])]),
+                Node("lib.rs", vec![]),
+            ],
+        );
+        assert_eq!(
+            render(&root, TreeGuides::Unicode, 2),
+            vec![
+                "src",
+                "├── bin",
+                "│   ├── main.rs",
+                "│   └── cli.rs",
+                "└── lib.rs"
+            ]
+        );
+        assert_eq!(
+            render(&root, TreeGuides::Ascii, 1),
+            vec!["src", "|-- bin (+2)", "`-- lib.rs"]
+        );
+        assert_eq!(render(&root, TreeGuides::Ascii, 0), vec!["src (+2)"]);
+        assert_eq!(node_label(&root, &[0, 1]), "cli.rs");
+    }
+}